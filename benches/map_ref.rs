@@ -0,0 +1,41 @@
+//! Compares `map` and `map_ref` reading one field of a large container value.
+//!
+//! Run with `cargo bench --bench map_ref`. The container fast path of
+//! `map_ref` borrows the stored value, while `map` clones it on every `get`.
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use nami::{Binding, Signal, SignalExt, binding};
+
+const LEN: usize = 100_000;
+const ITERATIONS: u32 = 1_000;
+
+fn measure(name: &str, f: impl Fn() -> usize) -> Duration {
+    // Warm up allocator and caches before timing.
+    for _ in 0..ITERATIONS / 10 {
+        black_box(f());
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f());
+    }
+    let per_iter = start.elapsed() / ITERATIONS;
+    println!("{name:>8}: {per_iter:?} per get");
+    per_iter
+}
+
+fn main() {
+    let source: Binding<Vec<u64>> = binding(vec![7_u64; LEN]);
+    let by_value = source.map(|values| values.len());
+    let by_ref = source.map_ref(Vec::len);
+
+    let map = measure("map", || by_value.get());
+    let map_ref = measure("map_ref", || by_ref.get());
+    println!(
+        "map_ref is {:.1}x faster on a {LEN}-element container",
+        map.as_secs_f64() / map_ref.as_secs_f64().max(f64::EPSILON)
+    );
+}
@@ -4,8 +4,11 @@
 
 use core::cell::RefCell;
 
-use crate::watcher::{Context, WatcherGuard, WatcherManager};
-use alloc::{collections::btree_map::BTreeMap, rc::Rc};
+use crate::{
+    Signal,
+    watcher::{Context, WatcherGuard, WatcherManager},
+};
+use alloc::{collections::btree_map::BTreeMap, rc::Rc, vec::Vec};
 
 /// A trait for dictionary-like data structures that support reactive watching of key-value pairs.
 pub trait Dictionary {
@@ -62,6 +65,136 @@ impl<K, V> Clone for Map<K, V> {
     }
 }
 
+impl<K, V> Default for Map<K, V> {
+    fn default() -> Self {
+        Self { map: Rc::default() }
+    }
+}
+
+impl<K: Ord + Clone + 'static, V: Clone + 'static> Map<K, V> {
+    /// Creates a new, empty reactive map.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a value for the given key, returning the previous value if any.
+    ///
+    /// Watchers of this key are notified with the new value.
+    pub fn insert(&self, key: K, value: V) -> Option<V> {
+        let mut map = self.map.borrow_mut();
+        let entry = map.entry(key).or_insert_with(|| MapValue {
+            value: None,
+            watchers: WatcherManager::new(),
+        });
+        let previous = entry.value.replace(value.clone());
+        let watchers = entry.watchers.clone();
+        // Release the borrow so watchers may read the map.
+        drop(map);
+        if !watchers.is_empty() {
+            watchers.notify(&Context::from(Some(value)));
+        }
+        previous
+    }
+
+    /// Removes the value for the given key, returning it if it was present.
+    ///
+    /// Watchers of this key are notified with `None` when a value was removed.
+    pub fn remove(&self, key: &K) -> Option<V> {
+        let mut map = self.map.borrow_mut();
+        let entry = map.get_mut(key)?;
+        let previous = entry.value.take()?;
+        let watchers = entry.watchers.clone();
+        drop(map);
+        if !watchers.is_empty() {
+            watchers.notify(&Context::from(None));
+        }
+        Some(previous)
+    }
+
+    /// Returns `true` if the given key currently holds a value.
+    #[must_use]
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.map
+            .borrow()
+            .get(key)
+            .is_some_and(|mv| mv.value.is_some())
+    }
+
+    /// Returns the number of keys currently holding a value.
+    ///
+    /// Keys without a value, such as those that were only watched or whose
+    /// value was removed, are not counted.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.map
+            .borrow()
+            .values()
+            .filter(|mv| mv.value.is_some())
+            .count()
+    }
+
+    /// Returns `true` if no key currently holds a value.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.map.borrow().values().all(|mv| mv.value.is_none())
+    }
+
+    /// Returns a snapshot of the keys currently holding a value, in order.
+    ///
+    /// Keys without a value are skipped, as in [`len`](Self::len).
+    #[must_use]
+    pub fn keys(&self) -> Vec<K> {
+        self.map
+            .borrow()
+            .iter()
+            .filter(|(_, mv)| mv.value.is_some())
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+
+    /// Returns a signal that is `true` while the given key holds a value.
+    ///
+    /// The signal is notified on every insert or removal of `key`, including
+    /// inserts that replace an existing value.
+    pub fn contains_key_signal(&self, key: K) -> impl Signal<Output = bool> {
+        ContainsKey {
+            map: self.clone(),
+            key,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ContainsKey<K, V> {
+    map: Map<K, V>,
+    key: K,
+}
+
+impl<K: Clone, V> Clone for ContainsKey<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+            key: self.key.clone(),
+        }
+    }
+}
+
+impl<K: Ord + Clone + 'static, V: Clone + 'static> Signal for ContainsKey<K, V> {
+    type Output = bool;
+    type Guard = crate::watcher::WatcherManagerGuard<Option<V>>;
+
+    fn get(&self) -> Self::Output {
+        self.map.contains_key(&self.key)
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        Dictionary::watch(&self.map, &self.key, move |ctx| {
+            watcher(ctx.map(|value| value.is_some()));
+        })
+    }
+}
+
 #[derive(Debug)]
 struct MapValue<V> {
     value: Option<V>,
@@ -92,6 +225,115 @@ impl<K: Ord + Clone + 'static, V: Clone + 'static> Dictionary for Map<K, V> {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{vec, vec::Vec};
+
+    #[test]
+    fn insert_notifies_key_watchers() {
+        let map: Map<&str, i32> = Map::new();
+        let seen: Rc<RefCell<Vec<Option<i32>>>> = Rc::default();
+
+        let _guard = {
+            let seen = seen.clone();
+            map.watch(&"a", move |ctx| seen.borrow_mut().push(ctx.into_value()))
+        };
+
+        assert_eq!(map.insert("a", 1), None);
+        assert_eq!(map.insert("b", 2), None);
+        assert_eq!(map.insert("a", 3), Some(1));
+
+        assert_eq!(Dictionary::get(&map, &"a"), Some(3));
+        assert_eq!(Dictionary::get(&map, &"b"), Some(2));
+        assert_eq!(*seen.borrow(), vec![Some(1), Some(3)]);
+    }
+
+    #[test]
+    fn remove_notifies_only_the_affected_key() {
+        let map: Map<&str, i32> = Map::new();
+        map.insert("a", 1);
+        map.insert("b", 2);
+        let seen: Rc<RefCell<Vec<Option<i32>>>> = Rc::default();
+
+        let _guard = {
+            let seen = seen.clone();
+            map.watch(&"a", move |ctx| seen.borrow_mut().push(ctx.into_value()))
+        };
+
+        assert_eq!(map.remove(&"b"), Some(2));
+        assert_eq!(map.remove(&"missing"), None);
+        assert!(seen.borrow().is_empty());
+
+        assert_eq!(map.remove(&"a"), Some(1));
+        assert_eq!(*seen.borrow(), vec![None]);
+        assert_eq!(
+            map.remove(&"a"),
+            None,
+            "removing an absent key does not notify"
+        );
+        assert_eq!(*seen.borrow(), vec![None]);
+    }
+
+    #[test]
+    fn contains_key_tracks_inserts_and_removals() {
+        let map: Map<&str, i32> = Map::new();
+        assert!(!map.contains_key(&"a"));
+
+        // Watching a key creates an empty slot without a value.
+        let _guard = map.watch(&"a", |_| {});
+        assert!(!map.contains_key(&"a"));
+
+        map.insert("a", 1);
+        assert!(map.contains_key(&"a"));
+        assert!(!map.contains_key(&"b"));
+
+        map.remove(&"a");
+        assert!(!map.contains_key(&"a"));
+    }
+
+    #[test]
+    fn watched_keys_without_values_are_not_counted() {
+        let map: Map<&str, i32> = Map::new();
+        let _guard = map.watch(&"pending", |_| {});
+        assert_eq!(map.len(), 0);
+        assert!(map.is_empty());
+        assert!(map.keys().is_empty());
+
+        map.insert("b", 2);
+        map.insert("a", 1);
+        assert_eq!(map.len(), 2);
+        assert!(!map.is_empty());
+        assert_eq!(map.keys(), vec!["a", "b"]);
+
+        map.remove(&"a");
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.keys(), vec!["b"]);
+    }
+
+    #[test]
+    fn contains_key_signal_flips_on_insert_and_remove() {
+        let map: Map<&str, i32> = Map::new();
+        let present = map.contains_key_signal("a");
+        assert!(!present.get());
+
+        let seen: Rc<RefCell<Vec<bool>>> = Rc::default();
+        let _guard = {
+            let seen = seen.clone();
+            present.watch(move |ctx| seen.borrow_mut().push(ctx.into_value()))
+        };
+
+        map.insert("a", 1);
+        assert!(present.get());
+        map.insert("b", 2);
+        assert_eq!(map.remove(&"a"), Some(1));
+        assert!(!present.get());
+        assert_eq!(map.remove(&"a"), None);
+
+        assert_eq!(*seen.borrow(), vec![true, false]);
+    }
+}
+
 #[cfg(feature = "std")]
 mod std_impls {
     extern crate std;
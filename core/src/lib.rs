@@ -2,6 +2,10 @@
 
 #![no_std]
 #![forbid(unsafe_code)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
 extern crate alloc;
 
 use crate::watcher::{Context, WatcherGuard};
@@ -9,6 +13,7 @@ use crate::watcher::{Context, WatcherGuard};
 /// Collection types for Nami.
 pub mod collection;
 pub mod dictionary;
+mod tuple;
 pub mod watcher;
 /// The core trait for reactive system.
 ///
@@ -0,0 +1,53 @@
+//! `Signal` implementations for tuples of signals.
+//!
+//! A tuple of up to six signals is itself a signal producing the tuple of
+//! their outputs, so `(a, b)` can be used wherever a zipped signal is needed.
+//! The flat zips in `nami::zip` delegate here.
+
+use core::cell::RefCell;
+
+use alloc::rc::Rc;
+
+use crate::{Signal, watcher::Context};
+
+macro_rules! impl_tuple_signal {
+    ($($ty:ident @ $idx:tt),+) => {
+        impl<$($ty),+> Signal for ($($ty,)+)
+        where
+            $($ty: Signal, $ty::Output: Clone,)+
+        {
+            type Output = ($($ty::Output,)+);
+            type Guard = ($($ty::Guard,)+);
+
+            fn get(&self) -> Self::Output {
+                ($(self.$idx.get(),)+)
+            }
+
+            /// Watches every element, notifying with the updated value alongside
+            /// the latest values of the others. Dropping the guard unsubscribes all.
+            fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+                let watcher = Rc::new(watcher);
+                let latest = Rc::new(RefCell::new(self.get()));
+
+                ($({
+                    let watcher = watcher.clone();
+                    let latest = latest.clone();
+                    self.$idx.watch(move |ctx: Context<$ty::Output>| {
+                        let ctx = ctx.map(|value| {
+                            let mut latest = latest.borrow_mut();
+                            latest.$idx = value;
+                            latest.clone()
+                        });
+                        watcher(ctx);
+                    })
+                },)+)
+            }
+        }
+    };
+}
+
+impl_tuple_signal!(A @ 0, B @ 1);
+impl_tuple_signal!(A @ 0, B @ 1, C @ 2);
+impl_tuple_signal!(A @ 0, B @ 1, C @ 2, D @ 3);
+impl_tuple_signal!(A @ 0, B @ 1, C @ 2, D @ 3, E @ 4);
+impl_tuple_signal!(A @ 0, B @ 1, C @ 2, D @ 3, E @ 4, F @ 5);
@@ -22,12 +22,15 @@
 //!
 //! Also, you can use [`nami::distinct`](nami::distinct) to create a distinct signal that only notifies when the value changes manually.
 
+#[cfg(feature = "std")]
+use alloc::collections::VecDeque;
 use alloc::{boxed::Box, collections::BTreeMap, rc::Rc, vec::Vec};
 use core::{
     any::{Any, TypeId, type_name},
-    cell::RefCell,
+    cell::{Cell, RefCell},
     fmt::Debug,
     num::NonZeroUsize,
+    sync::atomic::{AtomicUsize, Ordering},
 };
 
 /// A type-erased container for metadata that can be associated with computation results.
@@ -64,6 +67,11 @@ impl MetadataInner {
         // Here we choose `Rc` to allow cheap cloning when retrieving the value.
         self.0.insert(TypeId::of::<T>(), Rc::new(value));
     }
+
+    /// Removes the value of type `T`, returning whether one was present.
+    pub fn remove<T: 'static>(&mut self) -> bool {
+        self.0.remove(&TypeId::of::<T>()).is_some()
+    }
 }
 
 /// Type alias for a reference-counted watcher function.
@@ -91,6 +99,13 @@ impl<T> Context<T> {
         self
     }
 
+    /// Removes metadata of type `V` from this context, mirroring [`with`](Self::with).
+    #[must_use]
+    pub fn without<V: 'static>(mut self) -> Self {
+        self.metadata.remove::<V>();
+        self
+    }
+
     /// Consumes the context and returns the inner value.
     pub fn into_value(self) -> T {
         self.value
@@ -166,6 +181,29 @@ impl WatcherGuard for () {}
 
 impl<T1: WatcherGuard, T2: WatcherGuard> WatcherGuard for (T1, T2) {}
 
+impl<T1: WatcherGuard, T2: WatcherGuard, T3: WatcherGuard> WatcherGuard for (T1, T2, T3) {}
+
+impl<T1: WatcherGuard, T2: WatcherGuard, T3: WatcherGuard, T4: WatcherGuard> WatcherGuard
+    for (T1, T2, T3, T4)
+{
+}
+
+impl<T1: WatcherGuard, T2: WatcherGuard, T3: WatcherGuard, T4: WatcherGuard, T5: WatcherGuard>
+    WatcherGuard for (T1, T2, T3, T4, T5)
+{
+}
+
+impl<
+    T1: WatcherGuard,
+    T2: WatcherGuard,
+    T3: WatcherGuard,
+    T4: WatcherGuard,
+    T5: WatcherGuard,
+    T6: WatcherGuard,
+> WatcherGuard for (T1, T2, T3, T4, T5, T6)
+{
+}
+
 /// A utility struct that runs a cleanup function when dropped.
 #[derive(Debug)]
 pub struct OnDrop<F>(Option<F>)
@@ -185,7 +223,7 @@ where
     #[allow(clippy::needless_pass_by_value)]
     pub fn attach(guard: impl WatcherGuard, f: F) -> impl WatcherGuard {
         OnDrop::new(move || {
-            let _ = guard;
+            drop(guard);
             f();
         })
     }
@@ -211,6 +249,75 @@ impl<T: WatcherGuard + ?Sized> WatcherGuard for Rc<T> {}
 
 impl<F: FnOnce() + 'static> WatcherGuard for OnDrop<F> {}
 
+/// Extension methods for all [`WatcherGuard`] types.
+pub trait WatcherGuardExt: WatcherGuard + Sized {
+    /// Attaches an additional cleanup function to this guard.
+    ///
+    /// When the returned guard is dropped, this guard is dropped first,
+    /// then `f` is called.
+    fn on_drop(self, f: impl FnOnce() + 'static) -> impl WatcherGuard {
+        OnDrop::attach(self, f)
+    }
+}
+
+impl<G: WatcherGuard> WatcherGuardExt for G {}
+
+/// A token that owns watcher guards and drops them all at once when cancelled.
+///
+/// Clones share the same set of guards, so any clone can cancel the whole group.
+/// This is useful for tearing down a reactive subgraph in one step.
+#[derive(Clone)]
+pub struct CancellationToken {
+    guards: Rc<RefCell<Option<Vec<BoxWatcherGuard>>>>,
+}
+
+impl Debug for CancellationToken {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CancellationToken {
+    /// Creates a new, active cancellation token.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            guards: Rc::new(RefCell::new(Some(Vec::new()))),
+        }
+    }
+
+    /// Registers a guard to be dropped when the token is cancelled.
+    ///
+    /// If the token has already been cancelled, the guard is dropped immediately.
+    pub fn register(&self, guard: impl WatcherGuard) {
+        let mut guards = self.guards.borrow_mut();
+        if let Some(guards) = guards.as_mut() {
+            guards.push(Box::new(guard));
+        }
+    }
+
+    /// Cancels the token, dropping every registered guard.
+    pub fn cancel(&self) {
+        let guards = self.guards.borrow_mut().take();
+        // Drop outside the borrow so guard cleanup may touch this token.
+        drop(guards);
+    }
+
+    /// Returns `true` if the token has been cancelled.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.guards.borrow().is_none()
+    }
+}
+
 impl Metadata {
     /// Creates a new, empty metadata container.
     #[must_use]
@@ -251,11 +358,237 @@ impl Metadata {
     pub fn is_empty(&self) -> bool {
         self.0.0.is_empty()
     }
+
+    /// Returns `true` if a value of type `T` is present.
+    #[must_use]
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.0.0.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Removes the value of type `T`, returning whether one was present.
+    pub fn remove<T: 'static>(&mut self) -> bool {
+        self.0.remove::<T>()
+    }
+
+    /// Returns the [`TypeId`]s of the values present, in unspecified order.
+    pub fn type_ids(&self) -> impl Iterator<Item = TypeId> + '_ {
+        self.0.0.keys().copied()
+    }
 }
 
 /// A unique identifier for registered watchers.
 pub(crate) type WatcherId = NonZeroUsize;
 
+/// The default limit for nested [`WatcherManager::notify`] calls in debug builds.
+pub const DEFAULT_MAX_NOTIFY_DEPTH: usize = 100;
+
+static MAX_NOTIFY_DEPTH: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_NOTIFY_DEPTH);
+
+// Reactive graphs are per-thread, so each thread tracks its own nesting.
+#[cfg(all(debug_assertions, feature = "std"))]
+std::thread_local! {
+    static NOTIFY_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+#[cfg(all(debug_assertions, not(feature = "std")))]
+static NOTIFY_DEPTH: AtomicUsize = AtomicUsize::new(0);
+
+/// Sets how deeply [`WatcherManager::notify`] calls may nest before a debug build panics.
+///
+/// Nesting happens when a watcher updates another signal, whose watchers update
+/// another, and so on. A reactive cycle (A watches B watches A) nests forever,
+/// so exceeding the limit almost always means a cycle. Raise the limit for
+/// legitimately deep graphs. Release builds do not track depth.
+pub fn set_max_notify_depth(depth: usize) {
+    MAX_NOTIFY_DEPTH.store(depth, Ordering::Relaxed);
+}
+
+/// Tracks one level of notification nesting, undone on drop (including unwinding).
+#[cfg(debug_assertions)]
+struct NotifyDepth;
+
+#[cfg(debug_assertions)]
+impl NotifyDepth {
+    fn enter() -> Self {
+        #[cfg(feature = "std")]
+        let depth = NOTIFY_DEPTH.with(|depth| {
+            depth.set(depth.get() + 1);
+            depth.get()
+        });
+        #[cfg(not(feature = "std"))]
+        let depth = NOTIFY_DEPTH.fetch_add(1, Ordering::Relaxed) + 1;
+        let guard = Self;
+        let max = MAX_NOTIFY_DEPTH.load(Ordering::Relaxed);
+        assert!(
+            depth <= max,
+            "watcher notifications nested more than {max} levels deep; this usually means a reactive cycle (a signal's watcher updating a signal it depends on)"
+        );
+        guard
+    }
+}
+
+#[cfg(debug_assertions)]
+impl Drop for NotifyDepth {
+    fn drop(&mut self) {
+        #[cfg(feature = "std")]
+        NOTIFY_DEPTH.with(|depth| depth.set(depth.get() - 1));
+        #[cfg(not(feature = "std"))]
+        NOTIFY_DEPTH.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Restores the notification depth a queued job was deferred at while it runs,
+/// undone on drop (including unwinding).
+#[cfg(all(debug_assertions, feature = "std"))]
+struct ResumedDepth {
+    previous: usize,
+}
+
+#[cfg(all(debug_assertions, feature = "std"))]
+impl ResumedDepth {
+    fn enter(depth: usize) -> Self {
+        Self {
+            previous: NOTIFY_DEPTH.replace(depth),
+        }
+    }
+}
+
+#[cfg(all(debug_assertions, feature = "std"))]
+impl Drop for ResumedDepth {
+    fn drop(&mut self) {
+        NOTIFY_DEPTH.set(self.previous);
+    }
+}
+
+/// Jobs deferred by [`run_batched`] until the outermost [`batch`] ends.
+#[cfg(feature = "std")]
+#[derive(Default)]
+struct BatchQueue {
+    depth: usize,
+    jobs: VecDeque<QueuedJob>,
+}
+
+/// A job deferred by [`run_batched`].
+#[cfg(feature = "std")]
+struct QueuedJob {
+    key: usize,
+    /// The notification depth the job was queued at, so that a reactive cycle
+    /// inside a batch still reaches the depth limit instead of re-queueing
+    /// itself forever.
+    #[cfg(debug_assertions)]
+    depth: usize,
+    job: Box<dyn FnOnce()>,
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static BATCH: RefCell<BatchQueue> = RefCell::new(BatchQueue::default());
+}
+
+/// One level of [`batch`] nesting, undone on drop (including unwinding).
+#[cfg(feature = "std")]
+struct BatchScope;
+
+#[cfg(feature = "std")]
+impl BatchScope {
+    fn enter() -> Self {
+        BATCH.with_borrow_mut(|queue| queue.depth += 1);
+        Self
+    }
+
+    /// Runs queued jobs in order until none are left.
+    ///
+    /// The batch stays open while flushing, so jobs queued by other jobs are
+    /// appended (or merged with a job still waiting) instead of running early.
+    /// Each job runs at the notification depth it was queued at.
+    fn flush() {
+        while let Some(queued) = BATCH.with_borrow_mut(|queue| queue.jobs.pop_front()) {
+            #[cfg(debug_assertions)]
+            let _depth = ResumedDepth::enter(queued.depth);
+            (queued.job)();
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for BatchScope {
+    fn drop(&mut self) {
+        BATCH.with_borrow_mut(|queue| {
+            queue.depth -= 1;
+            if queue.depth == 0 {
+                // Only non-empty when unwinding out of the batch
+                queue.jobs.clear();
+            }
+        });
+    }
+}
+
+/// Runs `f`, deferring work scheduled with [`run_batched`] until it returns.
+///
+/// Nested calls only flush when the outermost batch ends. Without the `std`
+/// feature there is nowhere to queue work, so `f` simply runs unbatched.
+///
+/// # Panics
+///
+/// In debug builds, panics when deferred notifications keep queueing each
+/// other past the depth set by [`set_max_notify_depth`], which indicates a
+/// likely reactive cycle.
+pub fn batch<R>(f: impl FnOnce() -> R) -> R {
+    #[cfg(feature = "std")]
+    {
+        let scope = BatchScope::enter();
+        let result = f();
+        if BATCH.with_borrow(|queue| queue.depth) == 1 {
+            BatchScope::flush();
+        }
+        drop(scope);
+        result
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        f()
+    }
+}
+
+/// Runs `job` now, or at the end of the current [`batch`] if one is open.
+///
+/// A job queued under the same `key` as one still waiting replaces it in
+/// place, so each key runs at most once per batch, in first-queued order.
+/// Keys are usually the address of the state the job updates.
+pub fn run_batched(key: usize, job: impl FnOnce() + 'static) {
+    #[cfg(feature = "std")]
+    {
+        if BATCH.with_borrow(|queue| queue.depth == 0) {
+            job();
+            return;
+        }
+        #[cfg(debug_assertions)]
+        let depth = NOTIFY_DEPTH.get();
+        BATCH.with_borrow_mut(|queue| {
+            let job: Box<dyn FnOnce()> = Box::new(job);
+            if let Some(queued) = queue.jobs.iter_mut().find(|queued| queued.key == key) {
+                queued.job = job;
+                #[cfg(debug_assertions)]
+                {
+                    queued.depth = queued.depth.max(depth);
+                }
+            } else {
+                queue.jobs.push_back(QueuedJob {
+                    key,
+                    #[cfg(debug_assertions)]
+                    depth,
+                    job,
+                });
+            }
+        });
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = key;
+        job();
+    }
+}
+
 /// Manages a collection of watchers for a specific computation type.
 ///
 /// Provides functionality to register, notify, and cancel watchers.
@@ -293,9 +626,39 @@ impl<T: 'static> WatcherManager<T> {
         self.inner.borrow().is_empty()
     }
 
+    /// Returns the number of registered watchers.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.inner.borrow().map.len()
+    }
+
+    /// Returns a signal of the number of registered watchers.
+    ///
+    /// The signal notifies whenever a watcher is registered or cancelled.
+    /// Watchers of the count signal are not counted themselves.
+    #[must_use]
+    pub fn count_signal(&self) -> WatcherCount<T> {
+        WatcherCount {
+            manager: self.clone(),
+        }
+    }
+
+    /// Notifies watchers of [`count_signal`](Self::count_signal) with the current count.
+    fn notify_count(&self) {
+        let (counters, len) = {
+            let inner = self.inner.borrow();
+            (inner.counters.clone(), inner.map.len())
+        };
+        if let Some(counters) = counters {
+            counters.notify(&Context::from(len));
+        }
+    }
+
     /// Registers a new watcher and returns its unique identifier.
     pub fn register(&self, watcher: impl Fn(Context<T>) + 'static) -> WatcherId {
-        self.inner.borrow_mut().register(watcher)
+        let id = self.inner.borrow_mut().register(watcher);
+        self.notify_count();
+        id
     }
 
     /// Registers a watcher and returns a guard that will unregister it when dropped.
@@ -308,8 +671,66 @@ impl<T: 'static> WatcherManager<T> {
         WatcherManagerGuard { manager: this, id }
     }
 
+    /// Registers a watcher that can be paused and resumed through its guard.
+    ///
+    /// While paused, notifications skip the watcher without unregistering it.
+    pub fn register_pausable(&self, watcher: impl Fn(Context<T>) + 'static) -> PausableGuard<T> {
+        let enabled = Rc::new(Cell::new(true));
+        let guard = {
+            let enabled = enabled.clone();
+            self.register_as_guard(move |ctx| {
+                if enabled.get() {
+                    watcher(ctx);
+                }
+            })
+        };
+        PausableGuard {
+            _guard: guard,
+            enabled,
+        }
+    }
+
     /// Notifies all registered watchers with a preconstructed context.
+    ///
+    /// The watcher list is snapshotted before any watcher runs, so watchers may
+    /// register, cancel, or trigger nested notifications on this manager.
+    ///
+    /// Inside a [`batch`], the notification is deferred until the outermost
+    /// batch ends. Repeated notifications of the same manager are merged, so
+    /// its watchers run once with the last context.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics when nested notifications exceed the depth set by
+    /// [`set_max_notify_depth`], which indicates a likely reactive cycle.
     pub fn notify(&self, ctx: &Context<T>)
+    where
+        T: Clone,
+    {
+        #[cfg(feature = "std")]
+        if BATCH.with_borrow(|queue| queue.depth > 0) {
+            if self.is_empty() {
+                return;
+            }
+            let this = self.clone();
+            let ctx = ctx.clone();
+            let key = Rc::as_ptr(&self.inner).cast::<()>() as usize;
+            run_batched(key, move || this.notify_unbatched(&ctx));
+            return;
+        }
+        self.notify_unbatched(ctx);
+    }
+
+    /// Notifies all registered watchers immediately, bypassing any open [`batch`].
+    ///
+    /// Use this for event streams where every notification matters and
+    /// merging them into the last one would lose information.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics when nested notifications exceed the depth set by
+    /// [`set_max_notify_depth`], which indicates a likely reactive cycle.
+    pub fn notify_unbatched(&self, ctx: &Context<T>)
     where
         T: Clone,
     {
@@ -322,6 +743,9 @@ impl<T: 'static> WatcherManager<T> {
             return;
         }
 
+        #[cfg(debug_assertions)]
+        let _depth = NotifyDepth::enter();
+
         for watcher in watchers {
             watcher(ctx.clone());
         }
@@ -329,7 +753,48 @@ impl<T: 'static> WatcherManager<T> {
 
     /// Cancels a previously registered watcher by its identifier.
     pub fn cancel(&self, id: WatcherId) {
-        self.inner.borrow_mut().cancel(id);
+        let removed = self.inner.borrow_mut().cancel(id);
+        // Drop the watcher outside the borrow, as it may own guards of this manager.
+        if removed.is_some() {
+            drop(removed);
+            self.notify_count();
+        }
+    }
+}
+
+/// A signal of the number of watchers registered with a [`WatcherManager`].
+///
+/// Created by [`WatcherManager::count_signal`].
+#[derive(Debug)]
+pub struct WatcherCount<T> {
+    manager: WatcherManager<T>,
+}
+
+impl<T> Clone for WatcherCount<T> {
+    fn clone(&self) -> Self {
+        Self {
+            manager: self.manager.clone(),
+        }
+    }
+}
+
+impl<T: 'static> crate::Signal for WatcherCount<T> {
+    type Output = usize;
+    type Guard = WatcherManagerGuard<usize>;
+
+    fn get(&self) -> usize {
+        self.manager.len()
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<usize>) + 'static) -> Self::Guard {
+        let counters = self
+            .manager
+            .inner
+            .borrow_mut()
+            .counters
+            .get_or_insert_with(WatcherManager::new)
+            .clone();
+        counters.register_as_guard(watcher)
     }
 }
 
@@ -349,12 +814,47 @@ impl<T: 'static> Drop for WatcherManagerGuard<T> {
     }
 }
 
+/// A watcher guard whose watcher can be paused without unregistering it.
+///
+/// Created by [`WatcherManager::register_pausable`]. Dropping the guard
+/// unregisters the watcher, paused or not.
+#[must_use]
+#[derive(Debug)]
+pub struct PausableGuard<T: 'static> {
+    _guard: WatcherManagerGuard<T>,
+    enabled: Rc<Cell<bool>>,
+}
+
+impl<T> PausableGuard<T> {
+    /// Stops invoking the watcher until [`resume`](Self::resume) is called.
+    ///
+    /// Notifications delivered while paused are skipped, not replayed.
+    pub fn pause(&self) {
+        self.enabled.set(false);
+    }
+
+    /// Invokes the watcher again on subsequent notifications.
+    pub fn resume(&self) {
+        self.enabled.set(true);
+    }
+
+    /// Returns `true` while the watcher is paused.
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        !self.enabled.get()
+    }
+}
+
+impl<T> WatcherGuard for PausableGuard<T> {}
+
 /// Internal implementation of the watcher manager.
 ///
 /// Maintains the collection of watchers and handles identifier assignment.
 struct WatcherManagerInner<T> {
     id: WatcherId,
     map: BTreeMap<WatcherId, Watcher<T>>,
+    /// Watchers of the watcher count, created on first use.
+    counters: Option<WatcherManager<usize>>,
 }
 
 impl<T> Debug for WatcherManagerInner<T> {
@@ -368,6 +868,7 @@ impl<T> Default for WatcherManagerInner<T> {
         Self {
             id: WatcherId::MIN,
             map: BTreeMap::new(),
+            counters: None,
         }
     }
 }
@@ -400,8 +901,267 @@ impl<T: 'static> WatcherManagerInner<T> {
         self.map.values().cloned().collect()
     }
 
-    /// Cancels a watcher registration by its identifier.
-    pub fn cancel(&mut self, id: WatcherId) {
-        self.map.remove(&id);
+    /// Cancels a watcher registration, returning the watcher if it was registered.
+    pub fn cancel(&mut self, id: WatcherId) -> Option<Watcher<T>> {
+        self.map.remove(&id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Signal;
+    use alloc::{vec, vec::Vec};
+
+    #[test]
+    fn removing_metadata_keeps_other_entries() {
+        #[derive(Clone)]
+        struct RoutingTag(u8);
+        #[derive(Clone, Debug, PartialEq)]
+        struct Animation(&'static str);
+
+        let ctx = Context::from(1).with(RoutingTag(7)).with(Animation("fade"));
+        assert!(ctx.metadata().contains::<RoutingTag>());
+        assert_eq!(ctx.metadata().type_ids().count(), 2);
+        assert_eq!(
+            ctx.metadata().try_get::<RoutingTag>().map(|tag| tag.0),
+            Some(7)
+        );
+
+        let mut ctx = ctx.without::<RoutingTag>();
+        assert!(!ctx.metadata().contains::<RoutingTag>());
+        assert_eq!(
+            ctx.metadata().try_get::<Animation>(),
+            Some(Animation("fade"))
+        );
+        assert_eq!(
+            ctx.metadata().type_ids().collect::<Vec<_>>(),
+            vec![TypeId::of::<Animation>()]
+        );
+
+        assert!(ctx.metadata_mut().remove::<Animation>());
+        assert!(!ctx.metadata_mut().remove::<Animation>());
+        assert!(ctx.metadata().is_empty());
+    }
+
+    #[test]
+    fn count_signal_follows_registrations() {
+        let manager: WatcherManager<i32> = WatcherManager::new();
+        let count = manager.count_signal();
+        assert_eq!(count.get(), 0);
+
+        let seen: Rc<RefCell<Vec<usize>>> = Rc::default();
+        let _count_guard = {
+            let seen = seen.clone();
+            count.watch(move |ctx| seen.borrow_mut().push(ctx.into_value()))
+        };
+        assert_eq!(count.get(), 0, "count watchers are not counted");
+
+        let first = manager.register_as_guard(|_| {});
+        let second = manager.register_as_guard(|_| {});
+        assert_eq!(count.get(), 2);
+        drop(first);
+        drop(second);
+
+        assert_eq!(*seen.borrow(), vec![1, 2, 1, 0]);
+    }
+
+    #[test]
+    fn on_drop_runs_after_original_guard() {
+        let order: Rc<RefCell<Vec<&str>>> = Rc::default();
+
+        let original = {
+            let order = order.clone();
+            OnDrop::new(move || order.borrow_mut().push("original"))
+        };
+        let guard = {
+            let order = order.clone();
+            original.on_drop(move || order.borrow_mut().push("attached"))
+        };
+
+        assert!(order.borrow().is_empty());
+        drop(guard);
+        assert_eq!(*order.borrow(), vec!["original", "attached"]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn run_batched_merges_jobs_until_outermost_batch_ends() {
+        let log: Rc<RefCell<Vec<i32>>> = Rc::default();
+        let push = |value| {
+            let log = log.clone();
+            move || log.borrow_mut().push(value)
+        };
+
+        run_batched(1, push(0));
+        batch(|| {
+            run_batched(1, push(1));
+            run_batched(2, push(2));
+            batch(|| run_batched(1, push(3)));
+            assert_eq!(*log.borrow(), vec![0], "nested batches do not flush");
+        });
+
+        assert_eq!(*log.borrow(), vec![0, 3, 2]);
+    }
+
+    #[test]
+    fn cancellation_token_drops_all_guards() {
+        let first: WatcherManager<i32> = WatcherManager::new();
+        let second: WatcherManager<i32> = WatcherManager::new();
+        let calls = Rc::new(RefCell::new(0));
+
+        let token = CancellationToken::new();
+        for manager in [&first, &second] {
+            let calls = calls.clone();
+            token.register(manager.register_as_guard(move |_| *calls.borrow_mut() += 1));
+        }
+
+        first.notify(&Context::from(1));
+        second.notify(&Context::from(2));
+        assert_eq!(*calls.borrow(), 2);
+
+        let handle = token.clone();
+        handle.cancel();
+        assert!(token.is_cancelled());
+        assert!(first.is_empty());
+        assert!(second.is_empty());
+
+        first.notify(&Context::from(3));
+        second.notify(&Context::from(4));
+        assert_eq!(*calls.borrow(), 2);
+    }
+
+    #[test]
+    fn pausable_guard_skips_watcher_while_paused() {
+        let manager: WatcherManager<i32> = WatcherManager::new();
+        let seen: Rc<RefCell<Vec<i32>>> = Rc::default();
+        let guard = {
+            let seen = seen.clone();
+            manager.register_pausable(move |ctx| seen.borrow_mut().push(ctx.into_value()))
+        };
+
+        manager.notify(&Context::from(1));
+        guard.pause();
+        assert!(guard.is_paused());
+        manager.notify(&Context::from(2));
+        guard.resume();
+        manager.notify(&Context::from(3));
+        assert_eq!(*seen.borrow(), vec![1, 3]);
+
+        drop(guard);
+        assert!(manager.is_empty());
+    }
+
+    #[test]
+    fn cancellation_token_drops_late_registrations() {
+        let manager: WatcherManager<i32> = WatcherManager::new();
+        let token = CancellationToken::new();
+        token.cancel();
+
+        token.register(manager.register_as_guard(|_| {}));
+        assert!(manager.is_empty());
+    }
+
+    #[test]
+    fn on_drop_unregisters_watcher() {
+        let manager: WatcherManager<i32> = WatcherManager::new();
+        let cleaned = Rc::new(RefCell::new(false));
+
+        let guard = {
+            let cleaned = cleaned.clone();
+            manager
+                .register_as_guard(|_| {})
+                .on_drop(move || *cleaned.borrow_mut() = true)
+        };
+        assert!(!manager.is_empty());
+
+        drop(guard);
+        assert!(manager.is_empty());
+        assert!(*cleaned.borrow());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "usually means a reactive cycle")]
+    fn notify_cycle_panics_in_debug() {
+        let a: WatcherManager<i32> = WatcherManager::new();
+        let b: WatcherManager<i32> = WatcherManager::new();
+
+        let _a_to_b = {
+            let b = b.clone();
+            a.register_as_guard(move |ctx| b.notify(&ctx))
+        };
+        let _b_to_a = {
+            let a = a.clone();
+            b.register_as_guard(move |ctx| a.notify(&ctx))
+        };
+
+        a.notify(&Context::from(1));
+    }
+
+    #[test]
+    #[cfg(all(debug_assertions, feature = "std"))]
+    fn notify_depth_is_per_thread() {
+        use std::sync::{Arc, Barrier};
+
+        let nested = Arc::new(Barrier::new(2));
+        let checked = Arc::new(Barrier::new(2));
+        let other = {
+            let (nested, checked) = (nested.clone(), checked.clone());
+            std::thread::spawn(move || {
+                let depth: Vec<NotifyDepth> = (0..DEFAULT_MAX_NOTIFY_DEPTH)
+                    .map(|_| NotifyDepth::enter())
+                    .collect();
+                nested.wait();
+                checked.wait();
+                drop(depth);
+            })
+        };
+
+        nested.wait();
+        let manager: WatcherManager<i32> = WatcherManager::new();
+        let _guard = manager.register_as_guard(|_| {});
+        manager.notify(&Context::from(1));
+        checked.wait();
+        other.join().unwrap();
+    }
+
+    #[test]
+    #[cfg(all(debug_assertions, feature = "std"))]
+    #[should_panic(expected = "usually means a reactive cycle")]
+    fn batched_notify_cycle_panics_in_debug() {
+        let a: WatcherManager<i32> = WatcherManager::new();
+        let b: WatcherManager<i32> = WatcherManager::new();
+
+        let _a_to_b = {
+            let b = b.clone();
+            a.register_as_guard(move |ctx| b.notify(&ctx))
+        };
+        let _b_to_a = {
+            let a = a.clone();
+            b.register_as_guard(move |ctx| a.notify(&ctx))
+        };
+
+        batch(|| a.notify(&Context::from(1)));
+    }
+
+    #[test]
+    fn bounded_nesting_does_not_panic() {
+        let managers: Vec<WatcherManager<i32>> = (0..10).map(|_| WatcherManager::new()).collect();
+        let reached = Rc::new(RefCell::new(None));
+
+        let mut guards = Vec::new();
+        for pair in managers.windows(2) {
+            let next = pair[1].clone();
+            guards.push(pair[0].register_as_guard(move |ctx| next.notify(&ctx)));
+        }
+        let last = {
+            let reached = reached.clone();
+            managers[9].register_as_guard(move |ctx| *reached.borrow_mut() = Some(ctx.into_value()))
+        };
+
+        managers[0].notify(&Context::from(7));
+        assert_eq!(*reached.borrow(), Some(7));
+        drop((guards, last));
     }
 }
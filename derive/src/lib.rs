@@ -39,12 +39,53 @@ use syn::{
 /// assert_eq!(person.name, "Bob");
 /// assert_eq!(person.age, 25);
 /// ```
-#[proc_macro_derive(Project)]
+///
+/// # Lazy projection
+///
+/// With `#[project(lazy)]` on a struct with named fields, the generated
+/// `Projected` struct exposes one method per field instead of one field per
+/// field. Each field binding is created on first access and reused afterwards,
+/// so wide structs only pay for the fields that are actually used.
+///
+/// ```rust
+/// use nami::{binding, Binding, Signal, project::Project};
+/// use nami_derive::Project;
+///
+/// #[derive(Project, Clone)]
+/// #[project(lazy)]
+/// struct Settings {
+///     theme: String,
+///     font_size: u32,
+/// }
+///
+/// let settings: Binding<Settings> = binding(Settings {
+///     theme: "dark".to_string(),
+///     font_size: 14,
+/// });
+/// let projected = settings.project();
+/// projected.font_size().set(16); // only `font_size` is projected
+///
+/// assert_eq!(settings.get().font_size, 16);
+/// assert_eq!(projected.theme().get(), "dark");
+/// ```
+#[proc_macro_derive(Project, attributes(project))]
 pub fn derive_project(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
+    let lazy = match is_lazy_projection(&input) {
+        Ok(lazy) => lazy,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
     match &input.data {
         Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(fields_named) if lazy => derive_project_lazy_struct(&input, fields_named),
+            _ if lazy => syn::Error::new_spanned(
+                &input.ident,
+                "`#[project(lazy)]` requires a struct with named fields",
+            )
+            .to_compile_error()
+            .into(),
             Fields::Named(fields_named) => derive_project_struct(&input, fields_named),
             Fields::Unnamed(fields_unnamed) => derive_project_tuple_struct(&input, fields_unnamed),
             Fields::Unit => derive_project_unit_struct(&input),
@@ -62,6 +103,128 @@ pub fn derive_project(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Returns whether the struct is marked with `#[project(lazy)]`.
+fn is_lazy_projection(input: &DeriveInput) -> syn::Result<bool> {
+    let mut lazy = false;
+    for attr in &input.attrs {
+        if attr.path().is_ident("project") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("lazy") {
+                    lazy = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported project option, expected `lazy`"))
+                }
+            })?;
+        }
+    }
+    Ok(lazy)
+}
+
+/// Builds the bidirectional mapping from `source` to one of its fields.
+fn field_mapping(field_name: &syn::Ident) -> TokenStream2 {
+    quote! {
+        {
+            let source = source.clone();
+            ::nami::Binding::mapping(
+                &source,
+                |value| value.#field_name.clone(),
+                move |binding, value| {
+                    binding.with_mut(|b| {
+                        b.#field_name = value;
+                    });
+                },
+            )
+        }
+    }
+}
+
+/// Adds a `'static` bound to every type parameter.
+fn static_generics(generics: &syn::Generics) -> syn::Generics {
+    let mut generics = generics.clone();
+    for param in &mut generics.params {
+        if let syn::GenericParam::Type(type_param) = param {
+            type_param.bounds.push(syn::parse_quote!('static));
+        }
+    }
+    generics
+}
+
+fn derive_project_lazy_struct(input: &DeriveInput, fields: &syn::FieldsNamed) -> TokenStream {
+    let struct_name = &input.ident;
+    let (_impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let projected_struct_name = format_ident!("{struct_name}Projected");
+
+    let field_names: Vec<_> = fields
+        .named
+        .iter()
+        .filter_map(|f| f.ident.as_ref())
+        .collect();
+    if let Some(snapshot) = field_names.iter().find(|name| **name == "snapshot") {
+        return syn::Error::new_spanned(
+            snapshot,
+            "`#[project(lazy)]` generates a `snapshot` method, so a field cannot be named `snapshot`; rename the field or drop `lazy`",
+        )
+        .to_compile_error()
+        .into();
+    }
+    let field_types: Vec<_> = fields.named.iter().map(|f| &f.ty).collect();
+    let field_mappings = field_names.iter().map(|name| field_mapping(name));
+    let accessor_docs = field_names
+        .iter()
+        .map(|name| format!("Returns the binding for `{name}`, creating it on first access."));
+    let struct_doc = format!(
+        "Lazily projected version of `{struct_name}`; field bindings are created on first access."
+    );
+    let snapshot_doc = format!("Returns the current value of the source `{struct_name}`.");
+
+    let generics_with_static = static_generics(&input.generics);
+    let (impl_generics_with_static, _, _) = generics_with_static.split_for_impl();
+
+    let expanded = quote! {
+        #[doc = #struct_doc]
+        #[derive(Debug)]
+        pub struct #projected_struct_name #ty_generics #where_clause {
+            __nami_source: ::nami::Binding<#struct_name #ty_generics>,
+            #(#field_names: ::core::cell::OnceCell<::nami::Binding<#field_types>>,)*
+        }
+
+        impl #impl_generics_with_static #projected_struct_name #ty_generics #where_clause {
+            #(
+                #[doc = #accessor_docs]
+                #[must_use]
+                pub fn #field_names(&self) -> ::nami::Binding<#field_types> {
+                    self.#field_names
+                        .get_or_init(|| {
+                            let source = &self.__nami_source;
+                            #field_mappings
+                        })
+                        .clone()
+                }
+            )*
+
+            #[doc = #snapshot_doc]
+            #[must_use]
+            pub fn snapshot(&self) -> #struct_name #ty_generics {
+                ::nami::Signal::get(&self.__nami_source)
+            }
+        }
+
+        impl #impl_generics_with_static ::nami::project::Project for #struct_name #ty_generics #where_clause {
+            type Projected = #projected_struct_name #ty_generics;
+
+            fn project(source: &::nami::Binding<Self>) -> Self::Projected {
+                #projected_struct_name {
+                    __nami_source: source.clone(),
+                    #(#field_names: ::core::cell::OnceCell::new(),)*
+                }
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
 fn derive_project_struct(input: &DeriveInput, fields: &syn::FieldsNamed) -> TokenStream {
     let struct_name = &input.ident;
     let (_impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
@@ -81,32 +244,27 @@ fn derive_project_struct(input: &DeriveInput, fields: &syn::FieldsNamed) -> Toke
 
     // Generate the projection logic
     let field_projections = fields.named.iter().map(|field| {
-        let field_name = &field.ident;
+        let field_name = field.ident.as_ref().expect("named field");
+        let mapping = field_mapping(field_name);
         quote! {
-            #field_name: {
-                let source = source.clone();
-                ::nami::Binding::mapping(
-                    &source,
-                    |value| value.#field_name.clone(),
-                    move |binding, value| {
-                        binding.with_mut(|b| {
-                            b.#field_name = value;
-                        });
-                    },
-                )
-            }
+            #field_name: #mapping
         }
     });
 
     // Add lifetime bounds to generic parameters
-    let mut generics_with_static = input.generics.clone();
-    for param in &mut generics_with_static.params {
-        if let syn::GenericParam::Type(type_param) = param {
-            type_param.bounds.push(syn::parse_quote!('static));
-        }
-    }
+    let generics_with_static = static_generics(&input.generics);
     let (impl_generics_with_static, _, _) = generics_with_static.split_for_impl();
 
+    // Generate the snapshot logic
+    let field_snapshots = fields.named.iter().map(|field| {
+        let field_name = &field.ident;
+        quote! {
+            #field_name: ::nami::Signal::get(&self.#field_name)
+        }
+    });
+    let snapshot_doc =
+        format!("Collects the current value of every field binding into a new `{struct_name}`.");
+
     let expanded = quote! {
         /// Projected version of #struct_name with each field wrapped in a Binding.
         #[derive(Debug)]
@@ -114,6 +272,16 @@ fn derive_project_struct(input: &DeriveInput, fields: &syn::FieldsNamed) -> Toke
             #(#projected_fields,)*
         }
 
+        impl #impl_generics_with_static #projected_struct_name #ty_generics #where_clause {
+            #[doc = #snapshot_doc]
+            #[must_use]
+            pub fn snapshot(&self) -> #struct_name #ty_generics {
+                #struct_name {
+                    #(#field_snapshots,)*
+                }
+            }
+        }
+
         impl #impl_generics_with_static ::nami::project::Project for #struct_name #ty_generics #where_clause {
             type Projected = #projected_struct_name #ty_generics;
 
@@ -0,0 +1,156 @@
+//! # Activity-Gated Signal Implementation
+//!
+//! This module provides [`ActiveWhen`], a signal that only follows its source
+//! while an activity signal is `true`, e.g. for UI that is currently off-screen.
+
+use core::{
+    any::Any,
+    cell::{Cell, RefCell},
+};
+
+use alloc::rc::Rc;
+
+use crate::{
+    Signal,
+    watcher::{Context, WatcherManager, WatcherManagerGuard},
+};
+
+/// A signal that follows its source only while an activity signal is `true`.
+///
+/// While inactive, the upstream subscription is dropped, so the source is
+/// neither recomputed nor forwarded and `get()` returns the last value seen.
+/// On becoming active again, the source is read once and its current value
+/// is delivered to watchers.
+#[derive(Debug, Clone)]
+pub struct ActiveWhen<T> {
+    value: Rc<RefCell<T>>,
+    watchers: WatcherManager<T>,
+    _guard: Rc<dyn Any>,
+}
+
+impl<T: Clone + 'static> ActiveWhen<T> {
+    /// Creates a signal following `source` while `active` is `true`.
+    pub fn new<S, G>(source: &S, active: G) -> Self
+    where
+        S: Signal<Output = T>,
+        G: Signal<Output = bool>,
+    {
+        let value = Rc::new(RefCell::new(source.get()));
+        let watchers = WatcherManager::new();
+        let upstream: Rc<RefCell<Option<S::Guard>>> = Rc::default();
+        let is_active = Rc::new(Cell::new(false));
+
+        let follow = {
+            let source = source.clone();
+            let value = value.clone();
+            let watchers = watchers.clone();
+            move || {
+                let value = value.clone();
+                let watchers = watchers.clone();
+                source.watch(move |ctx: Context<T>| {
+                    *value.borrow_mut() = ctx.value().clone();
+                    watchers.notify(&ctx);
+                })
+            }
+        };
+
+        if active.get() {
+            is_active.set(true);
+            *upstream.borrow_mut() = Some(follow());
+        }
+
+        let active_guard = {
+            let source = source.clone();
+            let value = value.clone();
+            let watchers = watchers.clone();
+            let upstream = upstream.clone();
+            active.watch(move |ctx| {
+                let now = ctx.into_value();
+                if is_active.replace(now) == now {
+                    return;
+                }
+                if !now {
+                    let guard = upstream.borrow_mut().take();
+                    drop(guard);
+                    return;
+                }
+                let current = source.get();
+                *value.borrow_mut() = current.clone();
+                *upstream.borrow_mut() = Some(follow());
+                watchers.notify(&Context::from(current));
+            })
+        };
+
+        Self {
+            value,
+            watchers,
+            _guard: Rc::new((active, active_guard, upstream)),
+        }
+    }
+}
+
+impl<T: Clone + 'static> Signal for ActiveWhen<T> {
+    type Output = T;
+    type Guard = WatcherManagerGuard<T>;
+
+    fn get(&self) -> Self::Output {
+        self.value.borrow().clone()
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.watchers.register_as_guard(watcher)
+    }
+}
+
+impl_signal_ops!(ActiveWhen<T>, [T], T);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Binding, SignalExt, binding};
+    use alloc::{vec, vec::Vec};
+
+    #[test]
+    fn skips_recomputation_while_inactive() {
+        let source: Binding<i32> = binding(1);
+        let visible: Binding<bool> = binding(true);
+        let runs = Rc::new(Cell::new(0));
+        let squared = {
+            let runs = runs.clone();
+            source.map(move |value| {
+                runs.set(runs.get() + 1);
+                value * value
+            })
+        };
+        let shown = squared.active_when(visible.clone());
+        let seen: Rc<RefCell<Vec<i32>>> = Rc::default();
+        let seen_clone = seen.clone();
+        let _guard = shown.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        source.set(2);
+        visible.set(false);
+        runs.set(0);
+        source.set(3);
+        source.set(4);
+        assert_eq!(runs.get(), 0, "inactive signals should not recompute");
+        assert_eq!(shown.get(), 4, "the last active value is kept");
+
+        visible.set(true);
+        assert_eq!(runs.get(), 1);
+        source.set(5);
+
+        assert_eq!(*seen.borrow(), vec![4, 16, 25]);
+    }
+
+    #[test]
+    fn starting_inactive_waits_for_activation() {
+        let source: Binding<i32> = binding(1);
+        let active: Binding<bool> = binding(false);
+        let gated = source.active_when(active.clone());
+
+        source.set(2);
+        assert_eq!(gated.get(), 1);
+        active.set(true);
+        assert_eq!(gated.get(), 2);
+    }
+}
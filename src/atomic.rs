@@ -0,0 +1,202 @@
+//! # Atomic Mirrors
+//!
+//! Signals are `!Send`, so their values cannot be read from another thread
+//! directly. [`AtomicMirror`] is a one-way bridge: a watcher on the signal's
+//! thread stores every new value into an atomic, and any thread holding a clone
+//! of the mirror can load the latest value without going through an executor.
+//!
+//! Only available on targets with 64-bit atomics.
+//!
+//! ```rust
+//! use nami::{Binding, binding};
+//! use nami::atomic::AtomicMirror;
+//!
+//! let volume: Binding<u8> = binding(3);
+//! let (mirror, _guard) = AtomicMirror::of(&volume);
+//!
+//! volume.set(7);
+//! assert_eq!(mirror.load(), 7);
+//! ```
+
+use alloc::sync::Arc;
+use core::{
+    marker::PhantomData,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use crate::Signal;
+
+/// A `Copy` value that fits losslessly into 64 bits.
+///
+/// Implemented for the primitive integer types, `bool`, `char`, `f32`, and `f64`.
+pub trait AtomicValue: Copy + Send + Sync + 'static {
+    /// Encodes the value into its 64-bit representation.
+    fn into_bits(self) -> u64;
+
+    /// Decodes a value previously produced by [`AtomicValue::into_bits`].
+    fn from_bits(bits: u64) -> Self;
+}
+
+macro_rules! impl_atomic_value_int {
+    ($($ty:ty),*) => {
+        $(
+            impl AtomicValue for $ty {
+                #[allow(clippy::cast_sign_loss, clippy::cast_lossless)]
+                fn into_bits(self) -> u64 {
+                    self as u64
+                }
+
+                #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+                fn from_bits(bits: u64) -> Self {
+                    bits as Self
+                }
+            }
+        )*
+    };
+}
+
+impl_atomic_value_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl AtomicValue for bool {
+    fn into_bits(self) -> u64 {
+        u64::from(self)
+    }
+
+    fn from_bits(bits: u64) -> Self {
+        bits != 0
+    }
+}
+
+impl AtomicValue for char {
+    fn into_bits(self) -> u64 {
+        u64::from(self)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn from_bits(bits: u64) -> Self {
+        Self::from_u32(bits as u32).unwrap_or_default()
+    }
+}
+
+impl AtomicValue for f32 {
+    fn into_bits(self) -> u64 {
+        u64::from(self.to_bits())
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn from_bits(bits: u64) -> Self {
+        Self::from_bits(bits as u32)
+    }
+}
+
+impl AtomicValue for f64 {
+    fn into_bits(self) -> u64 {
+        self.to_bits()
+    }
+
+    fn from_bits(bits: u64) -> Self {
+        Self::from_bits(bits)
+    }
+}
+
+/// A thread-safe, read-only mirror of a signal's latest value.
+///
+/// Clones share the same storage and are `Send + Sync`, so they can be moved
+/// to other threads. Loads are relaxed: a reader sees some recent value, with
+/// no ordering guarantees relative to other memory.
+#[derive(Debug)]
+pub struct AtomicMirror<T> {
+    bits: Arc<AtomicU64>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for AtomicMirror<T> {
+    fn clone(&self) -> Self {
+        Self {
+            bits: self.bits.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: AtomicValue> AtomicMirror<T> {
+    /// Creates a mirror holding `initial`.
+    #[must_use]
+    pub fn new(initial: T) -> Self {
+        Self {
+            bits: Arc::new(AtomicU64::new(initial.into_bits())),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a mirror seeded with the signal's current value that follows its changes.
+    ///
+    /// The mirror stops updating once the returned guard is dropped.
+    pub fn of<S>(signal: &S) -> (Self, S::Guard)
+    where
+        S: Signal<Output = T>,
+    {
+        let mirror = Self::new(signal.get());
+        let guard = mirror.track(signal);
+        (mirror, guard)
+    }
+
+    /// Stores every value `signal` emits into this mirror until the guard is dropped.
+    pub fn track<S>(&self, signal: &S) -> S::Guard
+    where
+        S: Signal<Output = T>,
+    {
+        let this = self.clone();
+        signal.watch(move |ctx| this.store(ctx.into_value()))
+    }
+
+    /// Returns the most recently stored value.
+    #[must_use]
+    pub fn load(&self) -> T {
+        T::from_bits(self.bits.load(Ordering::Relaxed))
+    }
+
+    fn store(&self, value: T) {
+        self.bits.store(value.into_bits(), Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Binding, binding};
+
+    #[test]
+    fn mirror_is_readable_from_another_thread() {
+        let count: Binding<i64> = binding(-1);
+        let (mirror, _guard) = AtomicMirror::of(&count);
+
+        let remote = mirror.clone();
+        let seen = std::thread::spawn(move || remote.load()).join().unwrap();
+        assert_eq!(seen, -1);
+
+        count.set(i64::MIN);
+        let seen = std::thread::spawn(move || mirror.load()).join().unwrap();
+        assert_eq!(seen, i64::MIN);
+    }
+
+    #[test]
+    fn mirror_round_trips_non_integer_values() {
+        let ratio: Binding<f32> = binding(0.5);
+        let enabled: Binding<bool> = binding(false);
+        let (ratio_mirror, _ratio_guard) = AtomicMirror::of(&ratio);
+        let (enabled_mirror, enabled_guard) = AtomicMirror::of(&enabled);
+
+        ratio.set(-2.25);
+        enabled.set(true);
+        assert!((ratio_mirror.load() - -2.25).abs() < f32::EPSILON);
+        assert!(enabled_mirror.load());
+
+        drop(enabled_guard);
+        enabled.set(false);
+        assert!(
+            enabled_mirror.load(),
+            "mirror stops after the guard is dropped"
+        );
+    }
+}
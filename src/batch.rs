@@ -0,0 +1,120 @@
+//! # Batched Updates
+//!
+//! [`batch`] groups several updates so that work depending on them runs once
+//! when the batch ends, instead of once per update.
+
+/// Runs `f`, deferring notifications and batched recomputation until it returns.
+///
+/// Watchers of a binding or collection changed inside `f` run once, after `f`
+/// returns, with the final value. Values created with
+/// [`Computed::derived`](crate::Computed::derived) that depend on signals
+/// changed inside `f` likewise recompute once. Nested calls only flush when
+/// the outermost batch ends. Batching needs the `std` feature; without it `f`
+/// runs unbatched.
+///
+/// ```
+/// use nami::{Binding, Signal, batch, binding};
+/// use std::{cell::Cell, rc::Rc};
+///
+/// let width: Binding<i32> = binding(0);
+/// let redraws = Rc::new(Cell::new(0));
+/// let _guard = {
+///     let redraws = redraws.clone();
+///     width.watch(move |_| redraws.set(redraws.get() + 1))
+/// };
+///
+/// batch(|| {
+///     width.set(10);
+///     width.set(20);
+/// });
+/// assert_eq!(redraws.get(), 1);
+/// ```
+pub fn batch<R>(f: impl FnOnce() -> R) -> R {
+    nami_core::watcher::batch(f)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{Binding, Signal, SignalExt, binding, collection::List};
+    use alloc::{rc::Rc, vec, vec::Vec};
+    use core::cell::RefCell;
+
+    type Log<T> = Rc<RefCell<Vec<T>>>;
+
+    fn record<S: Signal>(signal: &S) -> (Log<S::Output>, S::Guard) {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let guard = {
+            let seen = seen.clone();
+            signal.watch(move |ctx| seen.borrow_mut().push(ctx.into_value()))
+        };
+        (seen, guard)
+    }
+
+    #[test]
+    fn each_watcher_sees_one_notification_with_final_value() {
+        let name: Binding<&str> = binding("a");
+        let count: Binding<i32> = binding(0);
+        let (names, _name_guard) = record(&name);
+        let (counts, _count_guard) = record(&count);
+
+        batch(|| {
+            name.set("b");
+            count.set(1);
+            name.set("c");
+            count.set(2);
+            count.set(3);
+            assert!(
+                names.borrow().is_empty(),
+                "notifications wait for the batch"
+            );
+        });
+
+        assert_eq!(*names.borrow(), vec!["c"]);
+        assert_eq!(*counts.borrow(), vec![3]);
+    }
+
+    #[test]
+    fn nested_batches_flush_at_the_outermost_level() {
+        let count: Binding<i32> = binding(0);
+        let (counts, _guard) = record(&count);
+
+        batch(|| {
+            count.set(1);
+            batch(|| count.set(2));
+            assert!(counts.borrow().is_empty());
+            count.set(3);
+        });
+
+        assert_eq!(*counts.borrow(), vec![3]);
+    }
+
+    #[test]
+    fn derived_signals_and_lists_notify_once() {
+        let count: Binding<i32> = binding(0);
+        let doubled = count.map(|n| n * 2);
+        let list = List::from(vec![1]);
+        let (values, _value_guard) = record(&doubled);
+        let (snapshots, _list_guard) = record(&list.signal());
+
+        batch(|| {
+            count.set(1);
+            list.push(2);
+            count.set(5);
+            list.push(3);
+        });
+
+        assert_eq!(*values.borrow(), vec![10]);
+        assert_eq!(*snapshots.borrow(), vec![vec![1, 2, 3]]);
+    }
+
+    #[test]
+    fn notifications_outside_a_batch_are_immediate() {
+        let count: Binding<i32> = binding(0);
+        let (counts, _guard) = record(&count);
+
+        count.set(1);
+        count.set(2);
+        assert_eq!(*counts.borrow(), vec![1, 2]);
+    }
+}
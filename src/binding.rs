@@ -7,7 +7,8 @@
 use core::{
     any::{Any, type_name},
     cell::RefCell,
-    fmt::Debug,
+    cmp::Ordering,
+    fmt::{Debug, Display},
     marker::PhantomData,
     mem::ManuallyDrop,
     ops::{
@@ -16,14 +17,19 @@ use core::{
     },
 };
 
-use alloc::{boxed::Box, rc::Rc};
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    rc::Rc,
+    vec::Vec,
+};
 use async_channel::{Sender, unbounded};
 use executor_core::{LocalExecutor, Task};
-use num_traits::Signed;
+use num_traits::{Signed, WrappingAdd, WrappingSub};
 
 use crate::{
-    Computed, Signal,
-    watcher::{BoxWatcherGuard, Context, WatcherManager},
+    Computed, Signal, SignalExt,
+    watcher::{BoxWatcherGuard, Context, WatcherCount, WatcherManager},
 };
 
 pub use nami_core::CustomBinding;
@@ -61,6 +67,13 @@ impl<T> Debug for Binding<T> {
     }
 }
 
+/// Formats the binding's current value.
+impl<T: Display + Clone + 'static> Display for Binding<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
+
 impl<T: 'static + Clone> Binding<T> {
     /// Creates a new binding from a value by wrapping it in a container.
     ///
@@ -68,6 +81,33 @@ impl<T: 'static + Clone> Binding<T> {
     pub fn container(value: T) -> Self {
         Self::custom(Container::new(value))
     }
+
+    /// Creates an independent binding seeded with the current value.
+    ///
+    /// Unlike [`clone`](Clone::clone), which returns another handle to the
+    /// *same* value, `fork` copies the value into a fresh [`Container`]. Setting
+    /// either binding afterwards does not affect the other, and watchers of
+    /// one are not notified by changes to the other.
+    ///
+    /// The fork is always a plain container, even when `self` is a mapping or
+    /// custom binding.
+    ///
+    /// # Example
+    /// ```
+    /// use nami::{binding, Binding};
+    ///
+    /// let original: Binding<i32> = binding(1);
+    /// let shared = original.clone();
+    /// let forked = original.fork();
+    ///
+    /// original.set(2);
+    /// assert_eq!(shared.get(), 2);
+    /// assert_eq!(forked.get(), 1);
+    /// ```
+    #[must_use]
+    pub fn fork(&self) -> Self {
+        Self::container(self.get())
+    }
 }
 
 impl<T: Default + Clone + 'static> Default for Binding<T> {
@@ -116,6 +156,10 @@ pub fn binding<T: 'static + Clone>(value: impl Into<T>) -> Binding<T> {
 
 impl_signal_binary_ops!(Binding<T>, [T], T);
 
+/// The value a binding held before [`Binding::update`], attached as metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreviousValue<T>(pub T);
+
 /// A guard that provides mutable access to a binding's value.
 ///
 /// When dropped, it will update the binding with the modified value.
@@ -244,6 +288,27 @@ impl<T: 'static> Binding<T> {
         self.0.set(value.into());
     }
 
+    /// Returns a signal of the number of watchers of a container binding.
+    ///
+    /// Returns `None` for bindings that are not backed by a [`Container`],
+    /// such as mappings, which have no watcher list of their own. See
+    /// [`Container::watcher_count_signal`].
+    #[must_use]
+    pub fn watcher_count_signal(&self) -> Option<WatcherCount<Rc<T>>> {
+        self.as_container().map(Container::watcher_count_signal)
+    }
+
+    /// Calls `f` with a reference to the current value.
+    ///
+    /// Container bindings lend their stored value without cloning it; other
+    /// bindings compute an owned value with [`get`](Self::get) and lend that.
+    pub fn with_ref<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        match self.as_container() {
+            Some(container) => container.with_ref(f),
+            None => f(&self.get()),
+        }
+    }
+
     fn as_container(&self) -> Option<&Container<T>> {
         let any = (self.0.as_ref()) as &dyn BindingImpl<Output = T> as &dyn Any;
         any.downcast_ref::<Container<T>>()
@@ -264,10 +329,7 @@ impl<T: 'static> Binding<T> {
             let updated = value.clone();
             drop(value);
             // notify watchers manually after releasing the RefCell borrow
-            if !container.watchers.is_empty() {
-                let context = Context::from(updated);
-                container.watchers.notify(&context);
-            }
+            container.notify(Context::from(updated));
             result
         } else {
             // fallback for non-container bindings
@@ -276,6 +338,68 @@ impl<T: 'static> Binding<T> {
         }
     }
 
+    /// Edits the value in place and returns the closure's result.
+    ///
+    /// This is the same operation as [`Binding::with_mut`], named for the common
+    /// case of reaching into nested data. Watchers are notified exactly once
+    /// after the closure returns, however deeply the edit reaches.
+    ///
+    /// ```
+    /// use nami::{Binding, binding};
+    ///
+    /// let grid: Binding<Vec<Vec<u8>>> = binding(vec![vec![0; 3]; 2]);
+    /// let previous = grid.modify(|rows| core::mem::replace(&mut rows[1][2], 9));
+    ///
+    /// assert_eq!(previous, 0);
+    /// assert_eq!(grid.get()[1][2], 9);
+    /// ```
+    pub fn modify<R>(&self, f: impl FnOnce(&mut T) -> R) -> R
+    where
+        T: Clone,
+    {
+        self.with_mut(f)
+    }
+
+    /// Edits the value in place, attaching the old value to the notification.
+    ///
+    /// Watchers can recover the value from before the edit with
+    /// `ctx.metadata().try_get::<PreviousValue<T>>()`, which is useful for
+    /// undo/redo.
+    ///
+    /// The previous value is only attached for container bindings. For other
+    /// bindings, such as mappings, it is computed with `get()` before the new
+    /// value is set, but the notification comes from the underlying source,
+    /// which has no way to carry it.
+    ///
+    /// ```
+    /// use nami::{Binding, Signal, binding, binding::PreviousValue};
+    ///
+    /// let count: Binding<i32> = binding(1);
+    /// let _guard = count.watch(|ctx| {
+    ///     let previous = ctx.metadata().try_get::<PreviousValue<i32>>();
+    ///     assert_eq!(previous, Some(PreviousValue(1)));
+    ///     assert_eq!(ctx.into_value(), 5);
+    /// });
+    /// count.update(|n| *n += 4);
+    /// ```
+    pub fn update(&self, f: impl FnOnce(&mut T))
+    where
+        T: Clone,
+    {
+        if let Some(container) = self.as_container() {
+            let mut value = container.value.borrow_mut();
+            let previous = value.clone();
+            f(&mut *value);
+            let updated = value.clone();
+            drop(value);
+            container.notify(Context::from(updated).with(PreviousValue(previous)));
+        } else {
+            let mut value = self.get();
+            f(&mut value);
+            self.set(value);
+        }
+    }
+
     /// Creates a bidirectional mapping between this binding and another type.
     ///
     /// The getter transforms values from this binding's type to the output type.
@@ -572,6 +696,30 @@ impl<T: Signed> Binding<T> {
     }
 }
 
+impl<T: WrappingAdd + WrappingSub + Copy + 'static> Binding<T> {
+    /// Adds `n` to the value, wrapping around at the type's bounds.
+    ///
+    /// Watchers are notified once.
+    ///
+    /// # Example
+    /// ```
+    /// use nami::{Binding, binding};
+    /// let frame: Binding<u8> = binding(250u8);
+    /// frame.wrapping_increment(10);
+    /// assert_eq!(frame.get(), 4);
+    /// ```
+    pub fn wrapping_increment(&self, n: T) {
+        self.with_mut(|value| *value = value.wrapping_add(&n));
+    }
+
+    /// Subtracts `n` from the value, wrapping around at the type's bounds.
+    ///
+    /// Watchers are notified once.
+    pub fn wrapping_decrement(&self, n: T) {
+        self.with_mut(|value| *value = value.wrapping_sub(&n));
+    }
+}
+
 macro_rules! impl_binding {
     ( $( #[$meta:meta] )* $ty:ident ) => {
         impl Binding<$ty> {
@@ -729,6 +877,29 @@ ops!(Shl, shl_assign, <<);
 ops!(Shr, shr_assign, >>);
 
 impl<T> Binding<Option<T>> {
+    /// Returns the contained value, initializing it with `f` if it is `None`.
+    ///
+    /// Watchers are notified only when the value is initialized; reading an
+    /// existing value does not notify.
+    ///
+    /// # Example
+    /// ```
+    /// let cache = nami::binding(None::<u32>);
+    /// assert_eq!(cache.get_or_insert_with(|| 7), 7);
+    /// assert_eq!(cache.get_or_insert_with(|| 9), 7);
+    /// ```
+    pub fn get_or_insert_with(&self, f: impl FnOnce() -> T) -> T
+    where
+        T: Clone + 'static,
+    {
+        if let Some(value) = self.get() {
+            return value;
+        }
+        let value = f();
+        self.set(Some(value.clone()));
+        value
+    }
+
     /// Creates a binding that unwraps the option or uses a default value from a closure.
     ///
     /// When setting values on the returned binding, they are wrapped in `Some`.
@@ -807,7 +978,7 @@ impl<T> Binding<Option<T>> {
             self,
             {
                 let equal = equal.clone();
-                move |value| value.as_ref().filter(|value| **value == equal).is_some()
+                move |value| value.as_ref().is_some_and(|value| *value == equal)
             },
             move |binding, value| {
                 if value {
@@ -818,6 +989,336 @@ impl<T> Binding<Option<T>> {
     }
 }
 
+impl<T: Clone + 'static> Binding<Vec<T>> {
+    /// Creates a binding projecting the element at `index`.
+    ///
+    /// Setting the returned binding writes the element back into this vector.
+    ///
+    /// # Panics
+    ///
+    /// Reading or writing the projection panics if `index` is out of bounds
+    /// for the vector at that time.
+    ///
+    /// # Example
+    /// ```
+    /// let items = nami::binding(vec![1, 2, 3]);
+    /// let second = items.index(1);
+    /// second.set(20);
+    /// assert_eq!(items.get(), vec![1, 20, 3]);
+    /// ```
+    #[must_use]
+    pub fn index(&self, index: usize) -> Binding<T> {
+        Self::mapping(
+            self,
+            move |items| items[index].clone(),
+            move |binding, value| {
+                binding.with_mut(|items| items[index] = value);
+            },
+        )
+    }
+
+    /// Replaces the vector only if `value` differs from the current contents.
+    ///
+    /// Watchers are not notified when the vectors are equal. For container
+    /// bindings the comparison borrows the stored vector without cloning it.
+    ///
+    /// # Example
+    /// ```
+    /// let items = nami::binding(vec![1, 2]);
+    /// items.set_distinct(vec![1, 2]); // no notification
+    /// items.set_distinct(vec![1, 2, 3]);
+    /// assert_eq!(items.get(), vec![1, 2, 3]);
+    /// ```
+    pub fn set_distinct(&self, value: Vec<T>)
+    where
+        T: PartialEq,
+    {
+        let unchanged = self.as_container().map_or_else(
+            || self.get() == value,
+            |container| *container.value.borrow() == value,
+        );
+        if !unchanged {
+            self.set(value);
+        }
+    }
+
+    /// Edits the vector in place, notifying watchers only if its contents changed.
+    ///
+    /// This is [`with_mut`](Self::with_mut) with a structural equality check
+    /// against a copy of the vector taken before the edit.
+    pub fn with_mut_distinct<R>(&self, f: impl FnOnce(&mut Vec<T>) -> R) -> R
+    where
+        T: PartialEq,
+    {
+        let mut value = self.get();
+        let before = value.clone();
+        let result = f(&mut value);
+        if value != before {
+            self.set(value);
+        }
+        result
+    }
+
+    /// Returns a signal of per-element bindings for editable list rendering.
+    ///
+    /// Each element binding is an [`index`](Self::index) projection that writes
+    /// back into this vector. The set of bindings is only rebuilt when the
+    /// vector's length changes, so in-place edits keep existing bindings.
+    ///
+    /// # Example
+    /// ```
+    /// use nami::Signal;
+    ///
+    /// let items = nami::binding(vec![1, 2]);
+    /// let bindings = items.items().get();
+    /// bindings[0].set(10);
+    /// assert_eq!(items.get(), vec![10, 2]);
+    /// ```
+    #[must_use]
+    pub fn items(&self) -> impl Signal<Output = Vec<Binding<T>>> {
+        let source = self.clone();
+        self.map(|items: Vec<T>| items.len())
+            .distinct()
+            .map(move |len| (0..len).map(|index| source.index(index)).collect())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> Binding<T>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + Clone + 'static,
+{
+    /// Applies a JSON merge patch (RFC 7386) to the binding's value.
+    ///
+    /// The current value is serialized, the patch is merged into it, and the
+    /// result is deserialized back and set, notifying watchers once. Objects are
+    /// merged recursively, `null` removes a key, and any other patch value
+    /// replaces the target. Fields absent from the patch keep their values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value cannot be serialized or the patched value
+    /// cannot be deserialized back into `T`. The binding is left unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Clone, Serialize, Deserialize)]
+    /// struct User {
+    ///     name: String,
+    ///     age: u32,
+    /// }
+    ///
+    /// let user: nami::Binding<User> = nami::binding(User { name: "Ada".into(), age: 36 });
+    /// user.apply_patch(&serde_json::json!({ "age": 37 })).unwrap();
+    /// assert_eq!(user.get().name, "Ada");
+    /// assert_eq!(user.get().age, 37);
+    /// ```
+    pub fn apply_patch(&self, patch: &serde_json::Value) -> Result<(), serde_json::Error> {
+        let mut value = serde_json::to_value(self.get())?;
+        merge_patch(&mut value, patch);
+        self.set(serde_json::from_value(value)?);
+        Ok(())
+    }
+}
+
+/// Merges `patch` into `target` following RFC 7386 semantics.
+#[cfg(feature = "serde")]
+fn merge_patch(target: &mut serde_json::Value, patch: &serde_json::Value) {
+    let serde_json::Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = serde_json::Value::Object(serde_json::Map::new());
+    }
+    let serde_json::Value::Object(target) = target else {
+        unreachable!("target was just made an object");
+    };
+    for (key, value) in patch {
+        if value.is_null() {
+            target.remove(key);
+        } else {
+            merge_patch(
+                target.entry(key.clone()).or_insert(serde_json::Value::Null),
+                value,
+            );
+        }
+    }
+}
+
+/// A single entry-level change between two versions of a map.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MapDiff<K, V> {
+    /// A key was added.
+    Inserted {
+        /// The inserted key.
+        key: K,
+        /// The inserted value.
+        value: V,
+    },
+    /// A key was removed.
+    Removed {
+        /// The removed key.
+        key: K,
+        /// The value held before removal.
+        value: V,
+    },
+    /// The value of an existing key changed.
+    Updated {
+        /// The updated key.
+        key: K,
+        /// The previous value.
+        old: V,
+        /// The new value.
+        new: V,
+    },
+}
+
+/// Computes the entry-level differences from `old` to `new`, in key order.
+fn diff_maps<K, V>(old: &BTreeMap<K, V>, new: &BTreeMap<K, V>) -> Vec<MapDiff<K, V>>
+where
+    K: Ord + Clone,
+    V: PartialEq + Clone,
+{
+    let mut diffs = Vec::new();
+    let mut old_iter = old.iter().peekable();
+    let mut new_iter = new.iter().peekable();
+
+    loop {
+        let ordering = match (old_iter.peek(), new_iter.peek()) {
+            (None, None) => break,
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some((old_key, _)), Some((new_key, _))) => old_key.cmp(new_key),
+        };
+        match ordering {
+            Ordering::Less => {
+                let (key, value) = old_iter.next().expect("peeked entry");
+                diffs.push(MapDiff::Removed {
+                    key: key.clone(),
+                    value: value.clone(),
+                });
+            }
+            Ordering::Greater => {
+                let (key, value) = new_iter.next().expect("peeked entry");
+                diffs.push(MapDiff::Inserted {
+                    key: key.clone(),
+                    value: value.clone(),
+                });
+            }
+            Ordering::Equal => {
+                let (key, old) = old_iter.next().expect("peeked entry");
+                let (_, new) = new_iter.next().expect("peeked entry");
+                if old != new {
+                    diffs.push(MapDiff::Updated {
+                        key: key.clone(),
+                        old: old.clone(),
+                        new: new.clone(),
+                    });
+                }
+            }
+        }
+    }
+    diffs
+}
+
+impl<K, V> Binding<BTreeMap<K, V>>
+where
+    K: Ord + Clone + 'static,
+    V: PartialEq + Clone + 'static,
+{
+    /// Watches the map for entry-level changes.
+    ///
+    /// Instead of receiving the whole map, the watcher is called once per
+    /// inserted, removed, or updated entry, in key order. Diffs are computed
+    /// against the value seen at the previous notification.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use nami::binding::MapDiff;
+    ///
+    /// let scores: nami::Binding<BTreeMap<&str, i32>> = nami::binding(BTreeMap::new());
+    /// let _guard = scores.watch_entries(|diff| println!("{diff:?}"));
+    /// scores.with_mut(|map| {
+    ///     map.insert("alice", 3);
+    /// });
+    /// ```
+    pub fn watch_entries(&self, watcher: impl Fn(MapDiff<K, V>) + 'static) -> BoxWatcherGuard {
+        let previous = Rc::new(RefCell::new(self.get()));
+        Signal::watch(self, move |ctx: Context<BTreeMap<K, V>>| {
+            let current = ctx.into_value();
+            let diffs = diff_maps(&previous.borrow(), &current);
+            *previous.borrow_mut() = current;
+            for diff in diffs {
+                watcher(diff);
+            }
+        })
+    }
+}
+
+impl<T: Ord + Clone + 'static> Binding<BTreeSet<T>> {
+    /// Adds `item` to the set, returning whether it was newly inserted.
+    ///
+    /// Watchers are notified once per call.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeSet;
+    ///
+    /// let tags: nami::Binding<BTreeSet<&str>> = nami::binding(BTreeSet::new());
+    /// assert!(tags.insert("rust"));
+    /// assert!(!tags.insert("rust"));
+    /// ```
+    pub fn insert(&self, item: T) -> bool {
+        self.with_mut(|set| set.insert(item))
+    }
+
+    /// Removes `item` from the set, returning whether it was present.
+    ///
+    /// Watchers are notified once per call.
+    pub fn remove(&self, item: &T) -> bool {
+        self.with_mut(|set| set.remove(item))
+    }
+
+    /// Inserts `item` if absent or removes it if present.
+    ///
+    /// Returns whether `item` is in the set afterwards. Watchers are notified
+    /// once per call. Named apart from [`Binding<bool>::toggle`] so that
+    /// `binding(false).toggle()` keeps inferring `bool`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeSet;
+    ///
+    /// let selected: nami::Binding<BTreeSet<u32>> = nami::binding(BTreeSet::new());
+    /// assert!(selected.toggle_item(7));
+    /// assert!(!selected.toggle_item(7));
+    /// ```
+    pub fn toggle_item(&self, item: T) -> bool {
+        self.with_mut(|set| {
+            if set.remove(&item) {
+                false
+            } else {
+                set.insert(item);
+                true
+            }
+        })
+    }
+
+    /// Returns a signal tracking whether `item` is in the set.
+    ///
+    /// The signal only notifies when membership of `item` changes, not on
+    /// every edit to the set.
+    #[must_use]
+    pub fn contains_signal(&self, item: T) -> impl Signal<Output = bool> {
+        self.map(move |set: BTreeSet<T>| set.contains(&item))
+            .distinct()
+    }
+}
+
 impl Binding<bool> {
     /// Toggles the boolean value and notifies watchers.
     ///
@@ -980,7 +1481,9 @@ where
 }
 
 impl<T> Clone for Binding<T> {
-    /// Creates a clone of this binding.
+    /// Creates another handle to this binding that shares its value.
+    ///
+    /// Use [`Binding::fork`] for an independent copy.
     fn clone(&self) -> Self {
         self.0.cloned_binding()
     }
@@ -994,8 +1497,11 @@ impl<T> Clone for Binding<T> {
 pub struct Container<T: 'static> {
     /// The contained value, wrapped in Reference-counted [`RefCell`] for interior mutability
     value: Rc<RefCell<T>>,
-    /// Manager for watchers that are interested in changes to the value
-    watchers: WatcherManager<T>,
+    /// Manager for watchers that are interested in changes to the value.
+    ///
+    /// Each notification shares one copy of the new value, so watchers from
+    /// [`Container::watch_ref`] can borrow it without a clone per watcher.
+    watchers: WatcherManager<Rc<T>>,
 }
 
 impl<T> From<T> for Container<T>
@@ -1021,6 +1527,48 @@ impl<T: 'static + Clone> Container<T> {
             watchers: WatcherManager::default(),
         }
     }
+
+    /// Registers a watcher that receives each new value by reference.
+    ///
+    /// Unlike [`Signal::watch`], the value is not cloned for the watcher, which
+    /// helps read-only watchers of large values. Reference watchers share the
+    /// regular watcher list, so they are batched, counted and ordered like any
+    /// other watcher.
+    pub fn watch_ref(&self, watcher: impl Fn(Context<&T>) + 'static) -> BoxWatcherGuard {
+        Box::new(
+            self.watchers
+                .register_as_guard(move |ctx: Context<Rc<T>>| watcher(ctx.as_deref())),
+        )
+    }
+}
+
+impl<T: 'static> Container<T> {
+    /// Returns a signal of the number of watchers registered with [`Signal::watch`].
+    ///
+    /// It notifies whenever a watcher is added or its guard dropped, which lets
+    /// a source stop expensive work while nobody is watching. Reference
+    /// watchers from [`watch_ref`](Self::watch_ref) are counted too.
+    #[must_use]
+    pub fn watcher_count_signal(&self) -> WatcherCount<Rc<T>> {
+        self.watchers.count_signal()
+    }
+
+    /// Notifies watchers of a new value, unless nobody is watching.
+    fn notify(&self, context: Context<T>) {
+        if self.watchers.is_empty() {
+            return;
+        }
+        self.watchers.notify(&context.map(Rc::new));
+    }
+
+    /// Calls `f` with a borrow of the stored value, without cloning it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `f` sets this container, since the value is borrowed.
+    pub fn with_ref<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.value.borrow())
+    }
 }
 
 impl<T: 'static + Clone> Signal for Container<T> {
@@ -1034,19 +1582,23 @@ impl<T: 'static + Clone> Signal for Container<T> {
 
     /// Registers a watcher to be notified when the value changes.
     fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
-        Box::new(self.watchers.register_as_guard(watcher))
+        Box::new(self.watchers.register_as_guard(move |ctx: Context<Rc<T>>| {
+            watcher(ctx.map(|value| T::clone(&value)));
+        }))
     }
 }
 
 impl<T: 'static + Clone> CustomBinding for Container<T> {
     /// Sets a new value and notifies watchers.
+    ///
+    /// No borrow is held while watchers run, so a watcher may call `set` on the
+    /// same container. Writes are last-writer-wins: the nested `set` replaces the
+    /// value and notifies every watcher immediately, after which the outer
+    /// notification resumes with the remaining watchers, which then see the
+    /// older value last. The container itself keeps the nested value.
     fn set(&self, value: T) {
         self.value.replace(value.clone());
-        if self.watchers.is_empty() {
-            return;
-        }
-        let context = Context::from(value);
-        self.watchers.notify(&context);
+        self.notify(Context::from(value));
     }
 }
 
@@ -1143,7 +1695,271 @@ impl<T> From<Binding<T>> for Computed<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloc::{string::String, vec, vec::Vec};
+    use alloc::{format, string::String, vec, vec::Vec};
+
+    #[test]
+    fn test_fork_is_independent_while_clone_shares() {
+        let original: Binding<Vec<i32>> = binding(vec![1]);
+        let shared = original.clone();
+        let forked = original.fork();
+
+        let notified = Rc::new(RefCell::new(0));
+        let notified_clone = notified.clone();
+        let _guard = forked.watch(move |_| *notified_clone.borrow_mut() += 1);
+
+        original.with_mut(|v| v.push(2));
+        assert_eq!(shared.get(), vec![1, 2]);
+        assert_eq!(forked.get(), vec![1]);
+        assert_eq!(*notified.borrow(), 0);
+
+        forked.set(vec![9]);
+        assert_eq!(original.get(), vec![1, 2]);
+        assert_eq!(*notified.borrow(), 1);
+    }
+
+    #[test]
+    fn test_fork_of_mapping_is_a_container() {
+        let source: Binding<i32> = binding(5);
+        let doubled = Binding::mapping(&source, |v| v * 2, |source, v: i32| source.set(v / 2));
+        let forked = doubled.fork();
+        assert!(forked.as_container().is_some());
+        assert_eq!(forked.get(), 10);
+
+        forked.set(20);
+        assert_eq!(source.get(), 5);
+    }
+
+    #[test]
+    fn test_wrapping_increment_wraps_at_max() {
+        let counter: Binding<u8> = binding(u8::MAX - 1);
+        let notified = Rc::new(RefCell::new(Vec::new()));
+        let notified_clone = notified.clone();
+        let _guard = counter.watch(move |ctx| notified_clone.borrow_mut().push(ctx.into_value()));
+
+        counter.wrapping_increment(1);
+        counter.wrapping_increment(2);
+        assert_eq!(*notified.borrow(), vec![u8::MAX, 1]);
+    }
+
+    #[test]
+    fn test_wrapping_decrement_wraps_at_min() {
+        let counter: Binding<i64> = binding(i64::MIN);
+        counter.wrapping_decrement(1);
+        assert_eq!(counter.get(), i64::MAX);
+
+        let unsigned: Binding<u32> = binding(0u32);
+        unsigned.wrapping_decrement(3);
+        assert_eq!(unsigned.get(), u32::MAX - 2);
+    }
+
+    #[test]
+    fn test_set_distinct_skips_equal_vectors() {
+        let items: Binding<Vec<i32>> = binding(vec![1, 2]);
+        let notified = Rc::new(RefCell::new(0));
+        let notified_clone = notified.clone();
+        let _guard = items.watch(move |_| *notified_clone.borrow_mut() += 1);
+
+        items.set_distinct(vec![1, 2]);
+        assert_eq!(*notified.borrow(), 0);
+        items.set_distinct(vec![2, 1]);
+        items.set_distinct(vec![2, 1]);
+        assert_eq!(*notified.borrow(), 1);
+
+        let first = items.index(0);
+        let projected = Binding::mapping(&items, |v| v, Binding::set);
+        projected.set_distinct(vec![2, 1]);
+        assert_eq!(
+            *notified.borrow(),
+            1,
+            "mapped bindings compare through get()"
+        );
+        first.set(5);
+        assert_eq!(*notified.borrow(), 2);
+    }
+
+    #[test]
+    fn test_with_mut_distinct_notifies_on_real_change() {
+        let items: Binding<Vec<i32>> = binding(vec![3, 1, 2]);
+        let notified = Rc::new(RefCell::new(0));
+        let notified_clone = notified.clone();
+        let _guard = items.watch(move |_| *notified_clone.borrow_mut() += 1);
+
+        items.with_mut_distinct(|v| v.sort_unstable());
+        items.with_mut_distinct(|v| v.sort_unstable());
+        let len = items.with_mut_distinct(|v| v.len());
+        assert_eq!(len, 3);
+        assert_eq!(items.get(), vec![1, 2, 3]);
+        assert_eq!(*notified.borrow(), 1);
+    }
+
+    #[test]
+    fn test_watcher_count_signal_tracks_guards() {
+        let source: Binding<i32> = binding(0);
+        let count = source.watcher_count_signal().unwrap();
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let _count_guard = count.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        let first = source.watch(|_| {});
+        let doubled = source.map(|n| n * 2);
+        let second = doubled.watch(|_| {});
+        assert_eq!(count.get(), 2);
+
+        drop(first);
+        drop(second);
+        assert_eq!(count.get(), 0);
+        assert_eq!(*seen.borrow(), vec![1, 2, 1, 0]);
+
+        let mapped = Binding::mapping(&source, |n| n, Binding::set);
+        assert!(mapped.watcher_count_signal().is_none());
+    }
+
+    #[test]
+    fn test_update_attaches_previous_value() {
+        let stack: Binding<Vec<i32>> = binding(vec![1]);
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let changes_clone = changes.clone();
+        let _guard = stack.watch(move |ctx| {
+            let previous = ctx.metadata().try_get::<PreviousValue<Vec<i32>>>();
+            changes_clone
+                .borrow_mut()
+                .push((previous.map(|p| p.0), ctx.into_value()));
+        });
+
+        stack.update(|items| items.push(2));
+        stack.update(Vec::clear);
+        stack.set(vec![7]);
+
+        assert_eq!(
+            *changes.borrow(),
+            vec![
+                (Some(vec![1]), vec![1, 2]),
+                (Some(vec![1, 2]), vec![]),
+                (None, vec![7]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_modify_nested_element_notifies_once() {
+        let grid: Binding<Vec<Vec<i32>>> = binding(vec![vec![1, 2], vec![3, 4]]);
+        let notified = Rc::new(RefCell::new(Vec::new()));
+        let notified_clone = notified.clone();
+        let _guard = grid.watch(move |ctx| notified_clone.borrow_mut().push(ctx.into_value()));
+
+        let sum = grid.modify(|rows| {
+            rows[1][0] *= 10;
+            rows[0].push(5);
+            rows.iter().flatten().sum::<i32>()
+        });
+
+        assert_eq!(sum, 42);
+        assert_eq!(*notified.borrow(), vec![vec![vec![1, 2, 5], vec![30, 4]]]);
+    }
+
+    #[test]
+    fn test_modify_through_mapping_notifies_once() {
+        let source: Binding<(Vec<i32>, bool)> = binding((vec![1], false));
+        let items = Binding::mapping(
+            &source,
+            |(items, _)| items,
+            |source, items| source.with_mut(|value| value.0 = items),
+        );
+        let count = Rc::new(RefCell::new(0));
+        let count_clone = count.clone();
+        let _guard = source.watch(move |_| *count_clone.borrow_mut() += 1);
+
+        let len = items.modify(|items| {
+            items.push(2);
+            items.len()
+        });
+
+        assert_eq!(len, 2);
+        assert_eq!(source.get().0, vec![1, 2]);
+        assert_eq!(*count.borrow(), 1);
+    }
+
+    #[test]
+    fn test_container_watch_ref_does_not_clone() {
+        #[derive(Debug)]
+        struct CloneCounter {
+            payload: i32,
+            clones: Rc<RefCell<usize>>,
+        }
+
+        impl Clone for CloneCounter {
+            fn clone(&self) -> Self {
+                *self.clones.borrow_mut() += 1;
+                Self {
+                    payload: self.payload,
+                    clones: self.clones.clone(),
+                }
+            }
+        }
+
+        let clones = Rc::new(RefCell::new(0));
+        let make = |payload| CloneCounter {
+            payload,
+            clones: clones.clone(),
+        };
+        let container = Container::new(make(0));
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let guard =
+            container.watch_ref(move |ctx| seen_clone.borrow_mut().push(ctx.value().payload));
+
+        CustomBinding::set(&container, make(1));
+        let baseline = *clones.borrow();
+        CustomBinding::set(&container, make(2));
+        assert_eq!(*seen.borrow(), vec![1, 2]);
+        assert_eq!(
+            *clones.borrow() - baseline,
+            1,
+            "only the stored copy is cloned"
+        );
+
+        let _value_guard = container.watch(|_| {});
+        let before = *clones.borrow();
+        CustomBinding::set(&container, make(3));
+        assert!(
+            *clones.borrow() - before > 1,
+            "by-value watchers do receive a clone"
+        );
+
+        drop(guard);
+        CustomBinding::set(&container, make(4));
+        assert_eq!(*seen.borrow(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_container_watch_ref_is_batched_and_counted() {
+        let container = Container::new(0);
+        let count = container.watcher_count_signal();
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let guard = container.watch_ref(move |ctx| seen_clone.borrow_mut().push(**ctx.value()));
+        assert_eq!(count.get(), 1);
+
+        crate::batch(|| {
+            CustomBinding::set(&container, 1);
+            CustomBinding::set(&container, 2);
+            CustomBinding::set(&container, 3);
+        });
+        assert_eq!(*seen.borrow(), vec![3]);
+
+        drop(guard);
+        assert_eq!(count.get(), 0);
+    }
+
+    #[test]
+    fn test_binding_display_shows_value() {
+        let count: Binding<i32> = binding(42);
+        assert_eq!(format!("{count}"), "42");
+
+        count.set(-7);
+        assert_eq!(format!("{count:>4}"), "  -7");
+    }
 
     #[test]
     fn test_binding_into_conversion() {
@@ -1309,4 +2125,222 @@ mod tests {
             "Dropping guard without mutation should not notify watchers"
         );
     }
+
+    #[test]
+    fn test_get_or_insert_with_initializes_once() {
+        use alloc::rc::Rc;
+        use core::cell::Cell;
+
+        let slot: Binding<Option<String>> = binding(None::<String>);
+        let notifications = Rc::new(Cell::new(0));
+        let notifications_clone = notifications.clone();
+        let _guard = slot.watch(move |_| notifications_clone.set(notifications_clone.get() + 1));
+        let calls = Cell::new(0);
+        let init = || {
+            calls.set(calls.get() + 1);
+            String::from("ready")
+        };
+
+        assert_eq!(slot.get_or_insert_with(init), "ready");
+        assert_eq!(slot.get_or_insert_with(init), "ready");
+        assert_eq!(slot.get(), Some(String::from("ready")));
+        assert_eq!(calls.get(), 1, "the initializer should only run once");
+        assert_eq!(
+            notifications.get(),
+            1,
+            "reading an initialized value should not notify"
+        );
+    }
+
+    #[test]
+    fn test_btree_set_toggle_updates_contains_signal() {
+        use alloc::rc::Rc;
+        use core::cell::RefCell;
+
+        let tags: Binding<BTreeSet<&str>> = binding(BTreeSet::new());
+        let has_rust = tags.contains_signal("rust");
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = has_rust.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        assert!(!has_rust.get());
+        assert!(tags.toggle_item("rust"));
+        assert!(has_rust.get());
+        assert!(tags.insert("go"));
+        assert!(!tags.toggle_item("rust"));
+        assert!(!has_rust.get());
+
+        assert_eq!(
+            *seen.borrow(),
+            vec![true, false],
+            "unrelated edits should not notify"
+        );
+    }
+
+    #[test]
+    fn test_btree_set_insert_and_remove_notify_once() {
+        use alloc::rc::Rc;
+        use core::cell::Cell;
+
+        let set: Binding<BTreeSet<u32>> = binding(BTreeSet::from([1]));
+        let notifications = Rc::new(Cell::new(0));
+        let notifications_clone = notifications.clone();
+        let _guard = set.watch(move |_| notifications_clone.set(notifications_clone.get() + 1));
+
+        assert!(set.insert(2));
+        assert!(set.remove(&1));
+        assert!(!set.remove(&1));
+
+        assert_eq!(set.get(), BTreeSet::from([2]));
+        assert_eq!(notifications.get(), 3);
+    }
+
+    #[test]
+    fn test_watch_entries_emits_map_diffs() {
+        use alloc::rc::Rc;
+        use core::cell::RefCell;
+
+        let map: Binding<BTreeMap<&str, i32>> = binding(BTreeMap::from([("a", 1), ("b", 2)]));
+        let diffs = Rc::new(RefCell::new(Vec::new()));
+        let diffs_clone = diffs.clone();
+        let _guard = map.watch_entries(move |diff| diffs_clone.borrow_mut().push(diff));
+
+        map.with_mut(|map| {
+            map.insert("c", 3);
+        });
+        map.with_mut(|map| {
+            map.insert("a", 10);
+            map.remove("b");
+        });
+        map.with_mut(|map| {
+            map.insert("c", 3);
+        });
+
+        assert_eq!(
+            *diffs.borrow(),
+            vec![
+                MapDiff::Inserted { key: "c", value: 3 },
+                MapDiff::Updated {
+                    key: "a",
+                    old: 1,
+                    new: 10
+                },
+                MapDiff::Removed { key: "b", value: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_items_project_each_element() {
+        use alloc::rc::Rc;
+        use core::cell::RefCell;
+
+        let source: Binding<Vec<i32>> = binding(vec![1, 2, 3]);
+        let items = source.items();
+
+        let rebuilds = Rc::new(RefCell::new(Vec::new()));
+        let rebuilds_clone = rebuilds.clone();
+        let _guard = items.watch(move |ctx| rebuilds_clone.borrow_mut().push(ctx.value().len()));
+
+        let bindings = items.get();
+        assert_eq!(bindings.len(), 3);
+        bindings[1].set(20);
+        bindings[2].add_assign(5);
+        assert_eq!(source.get(), vec![1, 20, 8]);
+        assert!(rebuilds.borrow().is_empty(), "same length keeps bindings");
+
+        source.with_mut(|items| items.push(4));
+        assert_eq!(*rebuilds.borrow(), vec![4]);
+        assert_eq!(items.get()[3].get(), 4);
+    }
+
+    #[test]
+    fn test_reentrant_set_from_watcher() {
+        use alloc::rc::Rc;
+        use core::cell::RefCell;
+
+        let value: Binding<i32> = binding(0);
+        let seen = Rc::new(RefCell::new(Vec::new()));
+
+        // Clamp writes to 10 from inside a watcher of the same binding.
+        let _clamp = {
+            let value = value.clone();
+            value.clone().watch(move |ctx| {
+                if *ctx.value() > 10 {
+                    value.set(10);
+                }
+            })
+        };
+        let _record = {
+            let seen = seen.clone();
+            value.watch(move |ctx| seen.borrow_mut().push(ctx.into_value()))
+        };
+
+        value.set(42);
+
+        assert_eq!(value.get(), 10, "the nested write wins");
+        assert_eq!(*seen.borrow(), vec![10, 42]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_apply_patch_updates_only_patched_fields() {
+        use alloc::rc::Rc;
+        use core::cell::RefCell;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        struct Settings {
+            title: String,
+            volume: u8,
+            tags: Vec<String>,
+            theme: Theme,
+        }
+
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        struct Theme {
+            dark: bool,
+            accent: String,
+        }
+
+        let settings: Binding<Settings> = binding(Settings {
+            title: String::from("Doc"),
+            volume: 3,
+            tags: vec![String::from("a")],
+            theme: Theme {
+                dark: false,
+                accent: String::from("blue"),
+            },
+        });
+        let notified = Rc::new(RefCell::new(0));
+        let notified_clone = notified.clone();
+        let _guard = settings.watch(move |_| *notified_clone.borrow_mut() += 1);
+
+        settings
+            .apply_patch(&serde_json::json!({ "volume": 7, "theme": { "dark": true } }))
+            .unwrap();
+
+        assert_eq!(
+            settings.get(),
+            Settings {
+                title: String::from("Doc"),
+                volume: 7,
+                tags: vec![String::from("a")],
+                theme: Theme {
+                    dark: true,
+                    accent: String::from("blue"),
+                },
+            }
+        );
+        assert_eq!(*notified.borrow(), 1);
+
+        let before = settings.get();
+        assert!(
+            settings
+                .apply_patch(&serde_json::json!({ "volume": "loud" }))
+                .is_err()
+        );
+        assert_eq!(settings.get(), before);
+        assert_eq!(*notified.borrow(), 1);
+    }
 }
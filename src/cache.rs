@@ -3,11 +3,14 @@
 //! This module provides a caching layer for reactive computations to improve performance
 //! by avoiding redundant calculations.
 
-use core::{any::Any, cell::RefCell};
+use core::{any::Any, cell::RefCell, fmt::Debug};
 
 use alloc::rc::Rc;
 
-use crate::{Signal, watcher::Context};
+use crate::{
+    Signal,
+    watcher::{Context, WatcherManager, WatcherManagerGuard},
+};
 
 /// A cached wrapper around a Signal that stores the last computed value.
 ///
@@ -49,6 +52,92 @@ where
             _guard: Rc::new(guard),
         }
     }
+
+    /// Creates a cache that does not keep `source` alive.
+    ///
+    /// The cache is seeded with the current value and only holds the watch
+    /// guard, so dropping every other handle to the source releases it. The
+    /// tradeoff is staleness: once the source is gone the cache keeps returning
+    /// the last value it saw and never notifies again.
+    pub fn weak(source: &C) -> WeakCached<C::Output> {
+        WeakCached::new(source)
+    }
+}
+
+/// Wraps a default-constructed source, e.g. for `#[derive(Default)]` structs.
+impl<C> Default for Cached<C>
+where
+    C: Signal + Default,
+    C::Output: Clone,
+{
+    fn default() -> Self {
+        Self::new(C::default())
+    }
+}
+
+/// A cache of a signal's latest value that does not own the signal.
+///
+/// Created by [`Cached::weak`]. Watchers registered on the cache are notified
+/// for as long as the source keeps emitting.
+pub struct WeakCached<T: 'static> {
+    cache: Rc<RefCell<T>>,
+    watchers: WatcherManager<T>,
+    upstream: Rc<dyn Any>,
+}
+
+impl<T: 'static> Clone for WeakCached<T> {
+    fn clone(&self) -> Self {
+        Self {
+            cache: self.cache.clone(),
+            watchers: self.watchers.clone(),
+            upstream: self.upstream.clone(),
+        }
+    }
+}
+
+impl<T: Debug + 'static> Debug for WeakCached<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("WeakCached")
+            .field("cache", &self.cache)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Clone + 'static> WeakCached<T> {
+    fn new<C>(source: &C) -> Self
+    where
+        C: Signal<Output = T>,
+    {
+        let cache = Rc::new(RefCell::new(source.get()));
+        let watchers = WatcherManager::new();
+        let guard = {
+            let cache = cache.clone();
+            let watchers = watchers.clone();
+            source.watch(move |context: Context<T>| {
+                cache.replace(context.value().clone());
+                watchers.notify(&context);
+            })
+        };
+
+        Self {
+            cache,
+            watchers,
+            upstream: Rc::new(guard),
+        }
+    }
+}
+
+impl<T: Clone + 'static> Signal for WeakCached<T> {
+    type Output = T;
+    type Guard = WatcherManagerGuard<T>;
+
+    fn get(&self) -> Self::Output {
+        self.cache.borrow().clone()
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.watchers.register_as_guard(watcher)
+    }
 }
 
 impl<C> Signal for Cached<C>
@@ -74,6 +163,88 @@ where
     }
 }
 
+/// A cache that also suppresses notifications carrying an unchanged value.
+///
+/// Created by [`SignalExt::memo`](crate::SignalExt::memo). `Memo` subscribes to
+/// its source once, keeps the latest value for `get`, and only notifies its
+/// watchers when a new value differs from the cached one. This differs from
+/// [`Distinct`](crate::distinct::Distinct), which filters per watcher but
+/// recomputes the source on every `get`.
+pub struct Memo<S: Signal> {
+    source: S,
+    cache: Rc<RefCell<S::Output>>,
+    watchers: WatcherManager<S::Output>,
+    upstream: Rc<dyn Any>,
+}
+
+impl<S: Signal> Clone for Memo<S> {
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source.clone(),
+            cache: self.cache.clone(),
+            watchers: self.watchers.clone(),
+            upstream: self.upstream.clone(),
+        }
+    }
+}
+
+impl<S: Signal + Debug> Debug for Memo<S>
+where
+    S::Output: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Memo")
+            .field("source", &self.source)
+            .field("cache", &self.cache)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S: Signal> Memo<S>
+where
+    S::Output: PartialEq + Clone,
+{
+    /// Creates a memo of `source`, seeded with its current value.
+    pub fn new(source: S) -> Self {
+        let cache = Rc::new(RefCell::new(source.get()));
+        let watchers = WatcherManager::new();
+        let guard = {
+            let cache = cache.clone();
+            let watchers = watchers.clone();
+            source.watch(move |context: Context<S::Output>| {
+                if *cache.borrow() == *context.value() {
+                    return;
+                }
+                cache.replace(context.value().clone());
+                watchers.notify(&context);
+            })
+        };
+
+        Self {
+            source,
+            cache,
+            watchers,
+            upstream: Rc::new(guard),
+        }
+    }
+}
+
+impl<S: Signal> Signal for Memo<S>
+where
+    S::Output: PartialEq + Clone,
+{
+    type Output = S::Output;
+    type Guard = WatcherManagerGuard<S::Output>;
+
+    fn get(&self) -> Self::Output {
+        self.cache.borrow().clone()
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.watchers.register_as_guard(watcher)
+    }
+}
+
 /// Creates a cached wrapper around the provided Signal.
 ///
 /// This is a convenience function equivalent to `Cached::new(source)`.
@@ -86,6 +257,7 @@ where
 }
 
 impl_signal_wrapper_ops!(Cached<C>, [C], C);
+impl_signal_ops!(WeakCached<T>, [T], T);
 
 #[cfg(test)]
 mod tests {
@@ -157,6 +329,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn default_instances_start_from_default_values() {
+        use crate::{Binding, collection::List};
+
+        #[derive(Default)]
+        struct Model {
+            count: Cached<Binding<i32>>,
+            name: Binding<alloc::string::String>,
+            items: List<u8>,
+        }
+
+        let model = Model::default();
+        assert_eq!(model.count.get(), 0);
+        assert!(model.name.get().is_empty());
+        assert!(model.items.snapshot().is_empty());
+    }
+
     #[test]
     fn cached_signal_updates_when_source_changes() {
         let signal = CountingSignal::new(1);
@@ -196,4 +385,57 @@ mod tests {
 
         assert_eq!(&*received.borrow(), &[3, 7]);
     }
+
+    #[test]
+    fn weak_cache_keeps_last_value_after_source_drops() {
+        let source: crate::Binding<i32> = crate::binding(1);
+        let cached = Cached::weak(&source);
+        assert_eq!(cached.get(), 1);
+
+        let received: Rc<RefCell<Vec<i32>>> = Rc::default();
+        let received_clone = received.clone();
+        let _guard =
+            cached.watch(move |context| received_clone.borrow_mut().push(context.into_value()));
+
+        source.set(2);
+        source.set(3);
+        drop(source);
+
+        assert_eq!(cached.get(), 3);
+        assert_eq!(&*received.borrow(), &[2, 3]);
+    }
+
+    #[test]
+    fn weak_cache_does_not_hold_source() {
+        let signal = CountingSignal::new(4);
+        let value = Rc::downgrade(&signal.value);
+        let cached = Cached::weak(&signal);
+        assert_eq!(signal.get_call_count(), 1);
+
+        drop(signal);
+        assert!(value.upgrade().is_none(), "the source should be released");
+        assert_eq!(cached.get(), 4);
+    }
+
+    #[test]
+    fn memo_skips_unchanged_values_and_caches_get() {
+        let signal = CountingSignal::new(1);
+        let parity = Memo::new(crate::map::map(signal.clone(), |n: i32| n % 2));
+        assert_eq!(signal.get_call_count(), 1, "seeded once on creation");
+
+        let received: Rc<RefCell<Vec<i32>>> = Rc::default();
+        let received_clone = received.clone();
+        let _guard =
+            parity.watch(move |context| received_clone.borrow_mut().push(context.into_value()));
+
+        signal.set(3);
+        signal.set(5);
+        signal.set(6);
+        signal.set(8);
+        signal.set(9);
+
+        assert_eq!(&*received.borrow(), &[0, 1]);
+        assert_eq!(parity.get(), 1);
+        assert_eq!(signal.get_call_count(), 1, "reads come from the cache");
+    }
 }
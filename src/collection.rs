@@ -63,21 +63,62 @@
 //! ```
 
 use core::{
-    cell::RefCell,
+    cell::{Cell, RefCell},
+    cmp::Ordering,
     ops::{Bound, RangeBounds},
 };
 pub use nami_core::collection::*;
 
-use alloc::{rc::Rc, vec::Vec};
-use nami_core::watcher::Context;
-
-use crate::watcher::{WatcherManager, WatcherManagerGuard};
+use alloc::{boxed::Box, collections::BTreeMap, rc::Rc, string::String, vec::Vec};
+use core::any::Any;
+use nami_core::{Signal, watcher::Context};
+
+use crate::{SignalExt, map::Map};
+
+use crate::watcher::{BoxWatcherGuard, WatcherManager, WatcherManagerGuard};
+
+/// A single mutation of a [`List`], delivered by [`List::watch_changes`].
+///
+/// Indices refer to the list as it was when the change was applied, so
+/// replaying the changes in order on a copy of the list keeps it in sync.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListChange<T> {
+    /// `value` was inserted at `index`.
+    Insert {
+        /// Position of the new element.
+        index: usize,
+        /// The inserted element.
+        value: T,
+    },
+    /// `value` was removed from `index`.
+    Remove {
+        /// Position the element was removed from.
+        index: usize,
+        /// The removed element.
+        value: T,
+    },
+    /// All elements were removed.
+    Clear,
+    /// The element at `index` changed from `old` to `new`.
+    Replace {
+        /// Position of the replaced element.
+        index: usize,
+        /// The element before the change.
+        old: T,
+        /// The element after the change.
+        new: T,
+    },
+}
 
 /// A reactive list that can be observed for changes.
 #[derive(Debug)]
 pub struct List<T> {
     vec: Rc<RefCell<Vec<T>>>,
     watchers: WatcherManager<Vec<T>>,
+    /// Watchers registered with [`List::watch_changes`].
+    changes: WatcherManager<ListChange<T>>,
+    /// Keeps an upstream subscription alive for lists derived from signals.
+    upstream: Option<Rc<dyn Any>>,
 }
 
 impl<T: 'static> From<Vec<T>> for List<T> {
@@ -85,6 +126,8 @@ impl<T: 'static> From<Vec<T>> for List<T> {
         Self {
             vec: Rc::new(RefCell::new(value)),
             watchers: WatcherManager::new(),
+            changes: WatcherManager::new(),
+            upstream: None,
         }
     }
 }
@@ -96,21 +139,45 @@ impl<T: 'static> List<T> {
         Self {
             vec: Rc::new(RefCell::new(Vec::new())),
             watchers: WatcherManager::new(),
+            changes: WatcherManager::new(),
+            upstream: None,
         }
     }
 
+    /// Creates a new empty reactive list with at least the given capacity.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::from(Vec::with_capacity(capacity))
+    }
+
+    /// Returns the number of elements the list can hold without reallocating.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.vec.borrow().capacity()
+    }
+
+    /// Reserves capacity for at least `additional` more elements.
+    ///
+    /// This does not change the contents, so watchers are not notified.
+    pub fn reserve(&self, additional: usize) {
+        self.vec.borrow_mut().reserve(additional);
+    }
+
     /// Adds an element to the end of the list.
     pub fn push(&self, value: T)
     where
         T: Clone,
     {
-        self.vec.borrow_mut().push(value);
-        if self.watchers.is_empty() {
-            return;
-        }
-        let snapshot = self.vec.borrow().clone();
-        let context = Context::from(snapshot);
-        self.watchers.notify(&context);
+        let index = {
+            let mut vec = self.vec.borrow_mut();
+            vec.push(value);
+            vec.len() - 1
+        };
+        self.emit_change(|| ListChange::Insert {
+            index,
+            value: self.vec.borrow()[index].clone(),
+        });
+        self.notify_snapshot();
     }
 
     /// Sorts the list in place.
@@ -119,12 +186,8 @@ impl<T: 'static> List<T> {
         T: Ord + Clone,
     {
         self.vec.borrow_mut().sort();
-        if self.watchers.is_empty() {
-            return;
-        }
-        let snapshot = self.vec.borrow().clone();
-        let context = Context::from(snapshot);
-        self.watchers.notify(&context);
+        self.emit_reset();
+        self.notify_snapshot();
     }
 
     /// Removes and returns the last element of the list.
@@ -134,13 +197,12 @@ impl<T: 'static> List<T> {
         T: Clone,
     {
         let result = self.vec.borrow_mut().pop();
-        if result.is_some() {
-            if self.watchers.is_empty() {
-                return result;
-            }
-            let snapshot = self.vec.borrow().clone();
-            let context = Context::from(snapshot);
-            self.watchers.notify(&context);
+        if let Some(value) = &result {
+            self.emit_change(|| ListChange::Remove {
+                index: self.vec.borrow().len(),
+                value: value.clone(),
+            });
+            self.notify_snapshot();
         }
         result
     }
@@ -151,12 +213,11 @@ impl<T: 'static> List<T> {
         T: Clone,
     {
         self.vec.borrow_mut().insert(index, value);
-        if self.watchers.is_empty() {
-            return;
-        }
-        let snapshot = self.vec.borrow().clone();
-        let context = Context::from(snapshot);
-        self.watchers.notify(&context);
+        self.emit_change(|| ListChange::Insert {
+            index,
+            value: self.vec.borrow()[index].clone(),
+        });
+        self.notify_snapshot();
     }
 
     /// Removes and returns the element at the specified index.
@@ -166,12 +227,11 @@ impl<T: 'static> List<T> {
         T: Clone,
     {
         let result = self.vec.borrow_mut().remove(index);
-        if self.watchers.is_empty() {
-            return result;
-        }
-        let snapshot = self.vec.borrow().clone();
-        let context = Context::from(snapshot);
-        self.watchers.notify(&context);
+        self.emit_change(|| ListChange::Remove {
+            index,
+            value: result.clone(),
+        });
+        self.notify_snapshot();
         result
     }
 
@@ -183,13 +243,146 @@ impl<T: 'static> List<T> {
         let was_empty = self.vec.borrow().is_empty();
         self.vec.borrow_mut().clear();
         if !was_empty {
-            if self.watchers.is_empty() {
-                return;
+            self.emit_change(|| ListChange::Clear);
+            self.notify_snapshot();
+        }
+    }
+
+    /// Swaps the elements at indices `a` and `b`, notifying watchers once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds, like [`slice::swap`].
+    pub fn swap(&self, a: usize, b: usize)
+    where
+        T: Clone,
+    {
+        self.vec.borrow_mut().swap(a, b);
+        if a != b {
+            for (index, old) in [(a, b), (b, a)] {
+                self.emit_change(|| {
+                    let vec = self.vec.borrow();
+                    ListChange::Replace {
+                        index,
+                        old: vec[old].clone(),
+                        new: vec[index].clone(),
+                    }
+                });
+            }
+        }
+        self.notify_snapshot();
+    }
+
+    /// Moves the element at `from` so that it ends up at index `to`,
+    /// shifting the elements in between and notifying watchers once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` or `to` are out of bounds. Indices are never clamped.
+    pub fn move_item(&self, from: usize, to: usize)
+    where
+        T: Clone,
+    {
+        {
+            let mut vec = self.vec.borrow_mut();
+            let len = vec.len();
+            assert!(
+                from < len && to < len,
+                "move_item indices (from is {from}, to is {to}) should be < len (is {len})"
+            );
+            if from < to {
+                vec[from..=to].rotate_left(1);
+            } else {
+                vec[to..=from].rotate_right(1);
             }
-            let context = Context::from(Vec::<T>::new());
-            self.watchers.notify(&context);
         }
+        if from != to {
+            let value = || self.vec.borrow()[to].clone();
+            self.emit_change(|| ListChange::Remove {
+                index: from,
+                value: value(),
+            });
+            self.emit_change(|| ListChange::Insert {
+                index: to,
+                value: value(),
+            });
+        }
+        self.notify_snapshot();
+    }
+
+    fn notify_snapshot(&self)
+    where
+        T: Clone,
+    {
+        if self.watchers.is_empty() {
+            return;
+        }
+        let snapshot = self.vec.borrow().clone();
+        let context = Context::from(snapshot);
+        self.watchers.notify(&context);
     }
+
+    /// Delivers the change built by `change` to [`watch_changes`](Self::watch_changes) watchers.
+    ///
+    /// Changes are never merged by [`batch`](crate::batch), since every event matters.
+    fn emit_change(&self, change: impl FnOnce() -> ListChange<T>)
+    where
+        T: Clone,
+    {
+        if self.changes.is_empty() {
+            return;
+        }
+        self.changes.notify_unbatched(&Context::from(change()));
+    }
+
+    /// Describes a wholesale rewrite as a [`ListChange::Clear`] followed by
+    /// one [`ListChange::Insert`] per element.
+    fn emit_reset(&self)
+    where
+        T: Clone,
+    {
+        if self.changes.is_empty() {
+            return;
+        }
+        self.emit_change(|| ListChange::Clear);
+        let items = self.vec.borrow().clone();
+        for (index, value) in items.into_iter().enumerate() {
+            self.emit_change(|| ListChange::Insert { index, value });
+        }
+    }
+
+    /// Retains only the elements for which `f` returns `true`.
+    ///
+    /// Watchers are notified once with the resulting snapshot, and only if
+    /// at least one element was removed.
+    pub fn retain(&self, f: impl FnMut(&T) -> bool)
+    where
+        T: Clone,
+    {
+        let mut f = f;
+        let track = !self.changes.is_empty();
+        let mut removed = Vec::new();
+        let mut index = 0;
+        self.vec.borrow_mut().retain(|item| {
+            let keep = f(item);
+            if keep {
+                index += 1;
+            } else {
+                removed.push((index, track.then(|| item.clone())));
+            }
+            keep
+        });
+        if removed.is_empty() {
+            return;
+        }
+        for (index, value) in removed {
+            if let Some(value) = value {
+                self.emit_change(|| ListChange::Remove { index, value });
+            }
+        }
+        self.notify_snapshot();
+    }
+
     /// Takes a snapshot of the current list contents.
     #[must_use]
     pub fn snapshot(&self) -> Vec<T>
@@ -199,6 +392,257 @@ impl<T: 'static> List<T> {
         self.vec.borrow().clone()
     }
 
+    /// Replaces the entire contents of the list, notifying watchers.
+    fn replace(&self, value: Vec<T>)
+    where
+        T: Clone,
+    {
+        *self.vec.borrow_mut() = value;
+        self.emit_reset();
+        self.notify_snapshot();
+    }
+
+    /// Replaces the element at `index`, notifying watchers.
+    fn set_item(&self, index: usize, value: T)
+    where
+        T: Clone,
+    {
+        let old = core::mem::replace(&mut self.vec.borrow_mut()[index], value);
+        self.emit_change(|| ListChange::Replace {
+            index,
+            old,
+            new: self.vec.borrow()[index].clone(),
+        });
+        self.notify_snapshot();
+    }
+
+    /// Registers a watcher receiving each mutation as a granular [`ListChange`].
+    ///
+    /// Unlike snapshot watchers, this avoids cloning the whole list per
+    /// mutation, so views can update incrementally. Reorderings such as
+    /// [`sort`](Self::sort) are reported as a [`ListChange::Clear`] followed by
+    /// an insert per element. The watcher is not called for the current
+    /// contents, and events are delivered immediately even inside a
+    /// [`batch`](crate::batch).
+    pub fn watch_changes(
+        &self,
+        watcher: impl Fn(ListChange<T>) + 'static,
+    ) -> WatcherManagerGuard<ListChange<T>> {
+        self.changes
+            .register_as_guard(move |ctx| watcher(ctx.into_value()))
+    }
+
+    /// Returns a signal of the whole list contents.
+    ///
+    /// The signal notifies whenever the list is modified, which makes it the
+    /// building block for values derived from the list.
+    #[must_use]
+    pub fn signal(&self) -> ListSignal<T> {
+        ListSignal { list: self.clone() }
+    }
+
+    /// Starts a lazy, reactive iterator pipeline over the list.
+    ///
+    /// Adapters compose into a single per-item step, so no intermediate
+    /// collections are built; see [`SignalIter`].
+    #[must_use]
+    pub fn signal_iter(&self) -> SignalIter<T, T> {
+        SignalIter {
+            source: self.signal(),
+            step: Rc::new(Some),
+        }
+    }
+
+    /// Returns a list that mirrors this one at most once per `frame` tick.
+    ///
+    /// Mutations are only marked as pending; each time `frame` emits, the
+    /// mirror is refreshed with the latest contents if anything changed since
+    /// the previous tick, so its watchers run once per frame however many
+    /// mutations happened in between.
+    ///
+    /// The subscriptions live as long as the returned list or any of its clones.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nami::{Binding, binding};
+    /// use nami::collection::{Collection, List};
+    ///
+    /// let list = List::from(vec![1]);
+    /// let frame: Binding<u64> = binding(0_u64);
+    /// let coalesced = list.frame_coalesced(frame.clone());
+    ///
+    /// list.push(2);
+    /// list.push(3);
+    /// assert_eq!(coalesced.len(), 1);
+    /// frame.set(1);
+    /// assert_eq!(coalesced.len(), 3);
+    /// ```
+    #[must_use]
+    pub fn frame_coalesced<F>(&self, frame: F) -> Self
+    where
+        T: Clone,
+        F: Signal,
+    {
+        let mut mirror = Self::from(self.snapshot());
+        let dirty = Rc::new(Cell::new(false));
+        let source_guard = {
+            let dirty = dirty.clone();
+            self.watchers.register_as_guard(move |_| dirty.set(true))
+        };
+        let frame_guard = {
+            let source = self.clone();
+            let synced = mirror.clone();
+            frame.watch(move |_| {
+                if dirty.replace(false) {
+                    synced.replace(source.snapshot());
+                }
+            })
+        };
+        mirror.upstream = Some(Rc::new((frame, source_guard, frame_guard)));
+        mirror
+    }
+
+    /// Returns the first `Some` produced by `f` over the list items,
+    /// recomputing on every list change.
+    pub fn find_map_signal<U, F>(&self, f: F) -> impl Signal<Output = Option<U>>
+    where
+        T: Clone,
+        U: 'static,
+        F: Fn(&T) -> Option<U> + Clone + 'static,
+    {
+        Map::new(self.signal(), move |items: Vec<T>| {
+            items.iter().find_map(&f)
+        })
+    }
+
+    /// Splits the list into items matching `predicate` and the rest,
+    /// recomputing both halves on every list change.
+    ///
+    /// Items keep their relative order within each half.
+    pub fn partition_signal<F>(&self, predicate: F) -> impl Signal<Output = (Vec<T>, Vec<T>)>
+    where
+        T: Clone,
+        F: Fn(&T) -> bool + Clone + 'static,
+    {
+        Map::new(self.signal(), move |items: Vec<T>| {
+            items.into_iter().partition(&predicate)
+        })
+    }
+
+    /// Groups the list items by key, recomputing the groups on every list change.
+    ///
+    /// Items keep their relative order within each group.
+    pub fn group_by<K, F>(&self, key_fn: F) -> impl Signal<Output = BTreeMap<K, Vec<T>>>
+    where
+        T: Clone,
+        K: Ord + 'static,
+        F: Fn(&T) -> K + Clone + 'static,
+    {
+        Map::new(self.signal(), move |items: Vec<T>| {
+            let mut groups: BTreeMap<K, Vec<T>> = BTreeMap::new();
+            for item in items {
+                groups.entry(key_fn(&item)).or_default().push(item);
+            }
+            groups
+        })
+    }
+
+    /// Returns a signal of the item with the minimum key, or `None` for an empty list.
+    ///
+    /// If several items share the minimum key, the first one is returned.
+    pub fn min_by_key<K, F>(&self, key_fn: F) -> impl Signal<Output = Option<T>>
+    where
+        T: Clone,
+        K: Ord + 'static,
+        F: Fn(&T) -> K + Clone + 'static,
+    {
+        Map::new(self.signal(), move |items: Vec<T>| {
+            items.into_iter().min_by_key(|item| key_fn(item))
+        })
+    }
+
+    /// Returns a signal of the item with the maximum key, or `None` for an empty list.
+    ///
+    /// If several items share the maximum key, the last one is returned.
+    pub fn max_by_key<K, F>(&self, key_fn: F) -> impl Signal<Output = Option<T>>
+    where
+        T: Clone,
+        K: Ord + 'static,
+        F: Fn(&T) -> K + Clone + 'static,
+    {
+        Map::new(self.signal(), move |items: Vec<T>| {
+            items.into_iter().max_by_key(|item| key_fn(item))
+        })
+    }
+
+    /// Returns a signal of the list contents sorted by `compare`.
+    ///
+    /// The list itself keeps its order; the view is re-sorted on every change.
+    /// The sort is stable, so equal items keep their relative order.
+    pub fn sorted_view<F>(&self, compare: F) -> impl Signal<Output = Vec<T>>
+    where
+        T: Clone,
+        F: Fn(&T, &T) -> Ordering + Clone + 'static,
+    {
+        Map::new(self.signal(), move |mut items: Vec<T>| {
+            items.sort_by(&compare);
+            items
+        })
+    }
+
+    /// Returns a signal of the index of the first item matching `predicate`.
+    ///
+    /// The index is recomputed on every change, so it follows the item when
+    /// the list is reordered and becomes `None` once no item matches.
+    pub fn index_of_signal<F>(&self, predicate: F) -> impl Signal<Output = Option<usize>>
+    where
+        T: Clone,
+        F: Fn(&T) -> bool + Clone + 'static,
+    {
+        Map::new(self.signal(), move |items: Vec<T>| {
+            items.iter().position(&predicate)
+        })
+    }
+
+    /// Returns a signal of the list contents split into rows of `size` items.
+    ///
+    /// The last row holds the remainder and may be shorter. The rows are
+    /// rebuilt on every change.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    #[must_use]
+    pub fn chunks_signal(&self, size: usize) -> impl Signal<Output = Vec<Vec<T>>>
+    where
+        T: Clone,
+    {
+        assert!(size > 0, "chunk size must be non-zero");
+        Map::new(self.signal(), move |items: Vec<T>| {
+            items.chunks(size).map(<[T]>::to_vec).collect()
+        })
+    }
+
+    /// Returns a signal of every overlapping window of `size` consecutive items.
+    ///
+    /// A list shorter than `size` has no windows and yields an empty `Vec`.
+    /// The windows are rebuilt on every change.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    #[must_use]
+    pub fn windows_signal(&self, size: usize) -> impl Signal<Output = Vec<Vec<T>>>
+    where
+        T: Clone,
+    {
+        assert!(size > 0, "window size must be non-zero");
+        Map::new(self.signal(), move |items: Vec<T>| {
+            items.windows(size).map(<[T]>::to_vec).collect()
+        })
+    }
+
     /// Returns an iterator over the list's items.
     ///
     /// Warning: This will clone the entire list, ensuring that modifications during iteration do not affect the iterator.
@@ -211,6 +655,41 @@ impl<T: 'static> List<T> {
     }
 }
 
+impl List<bool> {
+    /// Returns a signal that is `true` when every flag in the list is set.
+    ///
+    /// An empty list yields `true`.
+    #[must_use]
+    pub fn all_true(&self) -> impl Signal<Output = bool> {
+        Map::new(self.signal(), |flags: Vec<bool>| {
+            flags.iter().all(|flag| *flag)
+        })
+    }
+
+    /// Returns a signal that is `true` when at least one flag in the list is set.
+    ///
+    /// An empty list yields `false`.
+    #[must_use]
+    pub fn any_true(&self) -> impl Signal<Output = bool> {
+        Map::new(self.signal(), |flags: Vec<bool>| {
+            flags.iter().any(|flag| *flag)
+        })
+    }
+}
+
+impl List<String> {
+    /// Returns a signal of the items joined with `separator`.
+    ///
+    /// The joined string is rebuilt whenever the list changes.
+    #[must_use]
+    pub fn join_signal(&self, separator: &str) -> impl Signal<Output = String> {
+        let separator = String::from(separator);
+        Map::new(self.signal(), move |items: Vec<String>| {
+            items.join(&separator)
+        })
+    }
+}
+
 /// Iterator implementation for List<T>
 /// Tip: This method will attempt to avoid cloning the internal Vec if possible. However, if there are multiple references to the List, it will clone the Vec to ensure safety.
 impl<T: Clone + 'static> IntoIterator for List<T> {
@@ -242,10 +721,13 @@ impl<T> Clone for List<T> {
         Self {
             vec: self.vec.clone(),
             watchers: self.watchers.clone(),
+            changes: self.changes.clone(),
+            upstream: self.upstream.clone(),
         }
     }
 }
 
+/// Creates an empty list, so `List<T>` can be used in `#[derive(Default)]` structs.
 impl<T: 'static> Default for List<T> {
     fn default() -> Self {
         Self::new()
@@ -342,6 +824,258 @@ impl<T: Clone + 'static> Collection for List<T> {
     }
 }
 
+/// A signal over the whole contents of a [`List`].
+///
+/// Created by [`List::signal`].
+#[derive(Debug)]
+pub struct ListSignal<T> {
+    list: List<T>,
+}
+
+impl<T> Clone for ListSignal<T> {
+    fn clone(&self) -> Self {
+        Self {
+            list: self.list.clone(),
+        }
+    }
+}
+
+impl<T: Clone + 'static> Signal for ListSignal<T> {
+    type Output = Vec<T>;
+    type Guard = WatcherManagerGuard<Vec<T>>;
+
+    fn get(&self) -> Self::Output {
+        self.list.snapshot()
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.list.watchers.register_as_guard(watcher)
+    }
+}
+
+/// A lazy iterator pipeline over a [`List`], materialized with [`SignalIter::collect_signal`].
+///
+/// Created by [`List::signal_iter`]. The resulting signal re-runs the whole
+/// pipeline over the list contents whenever the list changes.
+///
+/// # Example
+///
+/// ```rust
+/// use nami::Signal;
+/// use nami::collection::List;
+///
+/// let list = List::from(vec![1, 2, 3, 4]);
+/// let even_squares = list
+///     .signal_iter()
+///     .filter(|n| n % 2 == 0)
+///     .map(|n| n * n)
+///     .collect_signal();
+///
+/// list.push(6);
+/// assert_eq!(even_squares.get(), vec![4, 16, 36]);
+/// ```
+pub struct SignalIter<T, U> {
+    source: ListSignal<T>,
+    step: Rc<dyn Fn(T) -> Option<U>>,
+}
+
+impl<T, U> core::fmt::Debug for SignalIter<T, U> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SignalIter").finish_non_exhaustive()
+    }
+}
+
+impl<T, U> Clone for SignalIter<T, U> {
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source.clone(),
+            step: self.step.clone(),
+        }
+    }
+}
+
+impl<T: Clone + 'static, U: 'static> SignalIter<T, U> {
+    /// Keeps only the items matching `predicate`.
+    #[must_use]
+    pub fn filter(self, predicate: impl Fn(&U) -> bool + 'static) -> Self {
+        let step = self.step;
+        Self {
+            source: self.source,
+            step: Rc::new(move |item| step(item).filter(|value| predicate(value))),
+        }
+    }
+
+    /// Transforms each item with `f`.
+    #[must_use]
+    pub fn map<V>(self, f: impl Fn(U) -> V + 'static) -> SignalIter<T, V> {
+        let step = self.step;
+        SignalIter {
+            source: self.source,
+            step: Rc::new(move |item| step(item).map(&f)),
+        }
+    }
+
+    /// Transforms each item with `f`, dropping the items for which it returns `None`.
+    #[must_use]
+    pub fn filter_map<V>(self, f: impl Fn(U) -> Option<V> + 'static) -> SignalIter<T, V> {
+        let step = self.step;
+        SignalIter {
+            source: self.source,
+            step: Rc::new(move |item| step(item).and_then(&f)),
+        }
+    }
+
+    /// Materializes the pipeline into a signal of the resulting items.
+    #[must_use]
+    pub fn collect_signal(self) -> impl Signal<Output = Vec<U>> {
+        let step = self.step;
+        Map::new(self.source, move |items: Vec<T>| {
+            items.into_iter().filter_map(|item| step(item)).collect()
+        })
+    }
+}
+
+/// Returns a signal of one page of `source`, driven by a page index signal.
+///
+/// The emitted slice covers `page * per_page .. (page + 1) * per_page`, clamped
+/// to the list bounds, and updates when either the list or the page changes.
+/// Pages past the end yield an empty vector.
+///
+/// # Example
+///
+/// ```rust
+/// use nami::{Binding, Signal, binding};
+/// use nami::collection::{List, page_slice};
+///
+/// let list = List::from(vec![1, 2, 3, 4, 5]);
+/// let page: Binding<usize> = binding(0_usize);
+/// let visible = page_slice(list, page.clone(), 2);
+///
+/// page.set(2);
+/// assert_eq!(visible.get(), vec![5]);
+/// ```
+pub fn page_slice<T, P>(source: List<T>, page: P, per_page: usize) -> impl Signal<Output = Vec<T>>
+where
+    T: Clone + 'static,
+    P: Signal<Output = usize>,
+{
+    crate::zip::zip(ListSignal { list: source }, page).map(move |(items, page): (Vec<T>, usize)| {
+        let start = page.saturating_mul(per_page).min(items.len());
+        let end = start.saturating_add(per_page).min(items.len());
+        items[start..end].to_vec()
+    })
+}
+
+/// Creates a [`List`] that mirrors a signal producing whole vectors.
+///
+/// The list starts with the signal's current value and its contents are
+/// replaced on every emission, notifying list watchers. This lets
+/// range-based [`Collection::watch`] be used on vector-valued signals.
+///
+/// The upstream subscription lives as long as the returned list or any of its clones.
+///
+/// # Example
+///
+/// ```rust
+/// use nami::{Binding, binding};
+/// use nami::collection::{Collection, list_from_signal};
+///
+/// let source: Binding<Vec<i32>> = binding(vec![1, 2]);
+/// let list = list_from_signal(source.clone());
+///
+/// source.set(vec![1, 2, 3]);
+/// assert_eq!(list.len(), 3);
+/// ```
+pub fn list_from_signal<S, T>(signal: S) -> List<T>
+where
+    S: Signal<Output = Vec<T>>,
+    T: Clone + 'static,
+{
+    let mut list = List::from(signal.get());
+    let synced = list.clone();
+    let guard = signal.watch(move |ctx| synced.replace(ctx.into_value()));
+    list.upstream = Some(Rc::new((signal, guard)));
+    list
+}
+
+/// A builder that assembles a collection from several signals, one item per signal.
+///
+/// Each item tracks its source signal: when a signal changes, the corresponding
+/// item is replaced and collection watchers are notified. This is useful for
+/// heterogeneous UI lists where every entry is driven by its own signal, e.g.
+/// an [`AnyCollection<Rc<dyn Widget>>`](AnyCollection).
+///
+/// Items must be `Clone` because collections hand out owned items; use `Rc`
+/// rather than `Box` for trait objects.
+///
+/// # Example
+///
+/// ```rust
+/// use nami::{Binding, binding, constant};
+/// use nami::collection::SignalCollection;
+///
+/// let title: Binding<&str> = binding("Inbox");
+/// let items = SignalCollection::new()
+///     .with(title.clone())
+///     .with(constant("Settings"))
+///     .build();
+///
+/// title.set("Inbox (3)");
+/// assert_eq!(items.get(0), Some("Inbox (3)"));
+/// ```
+pub struct SignalCollection<T> {
+    list: List<T>,
+    guards: Vec<BoxWatcherGuard>,
+}
+
+impl<T> core::fmt::Debug for SignalCollection<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SignalCollection")
+            .field("len", &self.guards.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Clone + 'static> Default for SignalCollection<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + 'static> SignalCollection<T> {
+    /// Creates an empty builder.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            list: List::new(),
+            guards: Vec::new(),
+        }
+    }
+
+    /// Appends an item driven by `signal`.
+    #[must_use]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn with(mut self, signal: impl Signal<Output = T>) -> Self {
+        let index = self.list.vec.borrow().len();
+        self.list.vec.borrow_mut().push(signal.get());
+        let list = self.list.clone();
+        let guard = signal.watch(move |ctx| list.set_item(index, ctx.into_value()));
+        self.guards.push(Box::new(guard));
+        self
+    }
+
+    /// Finishes building, returning a type-erased [`AnyCollection`].
+    ///
+    /// The collection is read-only, so every input keeps the slot it was
+    /// given by [`with`](Self::with).
+    #[must_use]
+    pub fn build(self) -> AnyCollection<T> {
+        let mut list = self.list;
+        list.upstream = Some(Rc::new(self.guards));
+        AnyCollection::new(list)
+    }
+}
+
 impl<T: 'static> FromIterator<T> for List<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         Self::from(iter.into_iter().collect::<Vec<_>>())
@@ -353,6 +1087,7 @@ mod tests {
     use super::*;
     use alloc::{rc::Rc, vec};
     use core::cell::{Cell, RefCell};
+    use nami_core::watcher::WatcherGuard;
 
     #[test]
     fn test_collection_trait_basic_operations() {
@@ -377,6 +1112,22 @@ mod tests {
         assert!(Collection::is_empty(&list2));
     }
 
+    #[test]
+    fn test_list_capacity_controls() {
+        let list: List<i32> = List::with_capacity(16);
+        assert!(list.capacity() >= 16);
+        assert!(list.snapshot().is_empty());
+
+        let notifications = Rc::new(Cell::new(0));
+        let count = notifications.clone();
+        let _guard = Collection::watch(&list, .., move |_| count.set(count.get() + 1));
+        assert_eq!(notifications.get(), 1, "initial snapshot only");
+
+        list.reserve(100);
+        assert!(list.capacity() >= 100);
+        assert_eq!(notifications.get(), 1, "reserving must not notify");
+    }
+
     #[test]
     fn test_list_from_vec() {
         let vec = vec![1, 2, 3, 4, 5];
@@ -445,6 +1196,171 @@ mod tests {
         assert!(Collection::is_empty(&list));
     }
 
+    fn record_notifications(list: &List<i32>) -> (Rc<RefCell<Vec<Vec<i32>>>>, impl WatcherGuard) {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let guard = list
+            .signal()
+            .watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+        (seen, guard)
+    }
+
+    #[test]
+    fn test_list_retain_removes_from_middle() {
+        let list = List::from(vec![1, 2, 3, 4, 5]);
+        let (seen, _guard) = record_notifications(&list);
+
+        list.retain(|n| *n == 1 || *n == 5);
+        assert_eq!(list.snapshot(), vec![1, 5]);
+        assert_eq!(*seen.borrow(), vec![vec![1, 5]]);
+    }
+
+    #[test]
+    fn test_list_retain_keeping_everything_does_not_notify() {
+        let list = List::from(vec![1, 2, 3]);
+        let (seen, _guard) = record_notifications(&list);
+
+        list.retain(|_| true);
+        assert_eq!(list.snapshot(), vec![1, 2, 3]);
+        assert!(seen.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_list_retain_removes_everything() {
+        let list = List::from(vec![1, 2, 3]);
+        let (seen, _guard) = record_notifications(&list);
+
+        list.retain(|_| false);
+        assert!(Collection::is_empty(&list));
+        assert_eq!(*seen.borrow(), vec![Vec::<i32>::new()]);
+    }
+
+    #[test]
+    fn test_list_swap_notifies_once() {
+        let list = List::from(vec![1, 2, 3, 4]);
+        let (seen, _guard) = record_notifications(&list);
+
+        list.swap(0, 3);
+        assert_eq!(*seen.borrow(), vec![vec![4, 2, 3, 1]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_list_swap_out_of_bounds_panics() {
+        List::from(vec![1, 2]).swap(0, 2);
+    }
+
+    #[test]
+    fn test_list_move_item_notifies_once_per_call() {
+        let list = List::from(vec![1, 2, 3, 4, 5]);
+        let (seen, _guard) = record_notifications(&list);
+
+        list.move_item(0, 3);
+        list.move_item(4, 1);
+        assert_eq!(
+            *seen.borrow(),
+            vec![vec![2, 3, 4, 1, 5], vec![2, 5, 3, 4, 1]]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "should be < len")]
+    fn test_list_move_item_out_of_bounds_panics() {
+        List::from(vec![1, 2, 3]).move_item(1, 3);
+    }
+
+    fn record_changes(list: &List<i32>) -> (Rc<RefCell<Vec<ListChange<i32>>>>, impl WatcherGuard) {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let guard = list.watch_changes(move |change| seen_clone.borrow_mut().push(change));
+        (seen, guard)
+    }
+
+    #[test]
+    fn test_watch_changes_reports_each_operation() {
+        let list = List::from(vec![1, 2]);
+        let (changes, _guard) = record_changes(&list);
+
+        list.push(3);
+        list.insert(0, 0);
+        assert_eq!(list.remove(1), 1);
+        assert_eq!(list.pop(), Some(3));
+        list.clear();
+        list.clear();
+
+        assert_eq!(
+            *changes.borrow(),
+            vec![
+                ListChange::Insert { index: 2, value: 3 },
+                ListChange::Insert { index: 0, value: 0 },
+                ListChange::Remove { index: 1, value: 1 },
+                ListChange::Remove { index: 2, value: 3 },
+                ListChange::Clear,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_watch_changes_reports_replacements_and_moves() {
+        let list = List::from(vec![1, 2, 3]);
+        let (changes, _guard) = record_changes(&list);
+
+        list.swap(0, 2);
+        list.move_item(0, 1);
+        list.retain(|n| *n != 1 && *n != 3);
+
+        assert_eq!(
+            *changes.borrow(),
+            vec![
+                ListChange::Replace {
+                    index: 0,
+                    old: 1,
+                    new: 3
+                },
+                ListChange::Replace {
+                    index: 2,
+                    old: 3,
+                    new: 1
+                },
+                ListChange::Remove { index: 0, value: 3 },
+                ListChange::Insert { index: 1, value: 3 },
+                ListChange::Remove { index: 1, value: 3 },
+                ListChange::Remove { index: 1, value: 1 },
+            ]
+        );
+        assert_eq!(list.snapshot(), vec![2]);
+    }
+
+    #[test]
+    fn test_watch_changes_replay_matches_list() {
+        let list = List::from(vec![5, 3, 8]);
+        let mirror = Rc::new(RefCell::new(list.snapshot()));
+        let _guard = {
+            let mirror = mirror.clone();
+            list.watch_changes(move |change| {
+                let mut mirror = mirror.borrow_mut();
+                match change {
+                    ListChange::Insert { index, value } => mirror.insert(index, value),
+                    ListChange::Remove { index, .. } => {
+                        mirror.remove(index);
+                    }
+                    ListChange::Clear => mirror.clear(),
+                    ListChange::Replace { index, new, .. } => mirror[index] = new,
+                }
+            })
+        };
+        let (snapshots, _snapshot_guard) = record_notifications(&list);
+
+        list.sort();
+        list.push(1);
+        list.swap(0, 3);
+        list.retain(|n| n % 2 == 1);
+        list.move_item(2, 0);
+
+        assert_eq!(*mirror.borrow(), list.snapshot());
+        assert_eq!(snapshots.borrow().len(), 5, "snapshot watchers still fire");
+    }
+
     #[test]
     fn test_list_clone() {
         let list1 = List::from(vec![1, 2, 3]);
@@ -504,6 +1420,388 @@ mod tests {
         assert_eq!(*notification_count.borrow(), 2);
     }
 
+    #[test]
+    fn test_list_from_signal_tracks_binding() {
+        let source: crate::Binding<Vec<i32>> = crate::binding(vec![1, 2, 3]);
+        let list = list_from_signal(source.clone());
+        assert_eq!(list.snapshot(), vec![1, 2, 3]);
+
+        source.set(vec![4, 5]);
+        assert_eq!(list.snapshot(), vec![4, 5]);
+        assert_eq!(Collection::len(&list), 2);
+    }
+
+    #[test]
+    fn test_list_from_signal_notifies_range_watchers() {
+        let source: crate::Binding<Vec<i32>> = crate::binding(vec![1, 2, 3]);
+        let list = list_from_signal(source.clone());
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = Collection::watch(&list, 1..3, move |ctx| {
+            seen_clone.borrow_mut().push(ctx.into_value().to_vec());
+        });
+
+        source.set(vec![7, 8, 9, 10]);
+        source.set(vec![0]);
+
+        assert_eq!(*seen.borrow(), vec![vec![2, 3], vec![8, 9], vec![]]);
+    }
+
+    #[test]
+    fn test_list_from_signal_keeps_subscription_across_clones() {
+        let source: crate::Binding<Vec<i32>> = crate::binding(vec![]);
+        let original = list_from_signal(source.clone());
+        let list = original.clone();
+        drop(original);
+
+        source.set(vec![42]);
+        assert_eq!(list.snapshot(), vec![42]);
+    }
+
+    #[test]
+    fn test_signal_collection_inputs_update_their_own_slot() {
+        let first: crate::Binding<i32> = crate::binding(1);
+        let second: crate::Binding<i32> = crate::binding(2);
+        let items = SignalCollection::new()
+            .with(first.clone())
+            .with(second.clone())
+            .build();
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = items.watch(.., move |ctx| {
+            seen_clone.borrow_mut().push(ctx.value().to_vec());
+        });
+
+        second.set(5);
+        first.set(3);
+        assert_eq!(items.len(), 2);
+        assert_eq!(*seen.borrow(), vec![vec![1, 2], vec![1, 5], vec![3, 5]]);
+    }
+
+    #[test]
+    fn test_signal_collection_with_trait_objects() {
+        use alloc::string::String;
+
+        trait Widget {
+            fn label(&self) -> String;
+        }
+
+        struct Text(&'static str);
+        impl Widget for Text {
+            fn label(&self) -> String {
+                self.0.into()
+            }
+        }
+
+        struct Counter(i32);
+        impl Widget for Counter {
+            fn label(&self) -> String {
+                alloc::format!("count: {}", self.0)
+            }
+        }
+
+        let count: crate::Binding<i32> = crate::binding(0);
+        let widgets: AnyCollection<Rc<dyn Widget>> = SignalCollection::new()
+            .with(crate::constant(Rc::new(Text("header")) as Rc<dyn Widget>))
+            .with(crate::SignalExt::map(&count, |n| {
+                Rc::new(Counter(n)) as Rc<dyn Widget>
+            }))
+            .build();
+
+        let labels = Rc::new(RefCell::new(Vec::new()));
+        let labels_clone = labels.clone();
+        let _guard = widgets.watch(.., move |ctx| {
+            let current: Vec<_> = ctx.value().iter().map(|widget| widget.label()).collect();
+            labels_clone.borrow_mut().push(current);
+        });
+
+        count.set(2);
+
+        assert_eq!(widgets.len(), 2);
+        assert_eq!(widgets.get(1).unwrap().label(), "count: 2");
+        assert_eq!(
+            *labels.borrow(),
+            vec![
+                vec![String::from("header"), String::from("count: 0")],
+                vec![String::from("header"), String::from("count: 2")],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_list_group_by_parity() {
+        let list = List::from(vec![1, 2, 3, 4]);
+        let groups = list.group_by(|value| value % 2 == 0);
+
+        let expected = |odd: Vec<i32>, even: Vec<i32>| BTreeMap::from([(false, odd), (true, even)]);
+        assert_eq!(groups.get(), expected(vec![1, 3], vec![2, 4]));
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = groups.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        list.push(5);
+        let _ = list.remove(1);
+
+        assert_eq!(
+            *seen.borrow(),
+            vec![
+                expected(vec![1, 3, 5], vec![2, 4]),
+                expected(vec![1, 3, 5], vec![4])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_list_max_by_key_updates() {
+        let list = List::from(vec!["kiwi", "banana", "fig"]);
+        let longest = list.max_by_key(|word| word.len());
+        let shortest = list.min_by_key(|word| word.len());
+        assert_eq!(longest.get(), Some("banana"));
+        assert_eq!(shortest.get(), Some("fig"));
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = longest.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        list.push("watermelon");
+        let _ = list.pop();
+        let _ = list.remove(1);
+        assert_eq!(
+            *seen.borrow(),
+            vec![Some("watermelon"), Some("banana"), Some("kiwi")]
+        );
+
+        list.clear();
+        assert_eq!(longest.get(), None);
+        assert_eq!(shortest.get(), None);
+    }
+
+    #[test]
+    fn test_signal_iter_filter_map_recomputes() {
+        let list = List::from(vec![1, 2, 3, 4]);
+        let labels = list
+            .signal_iter()
+            .filter(|n| *n > 1)
+            .map(|n| n * 10)
+            .filter(|n| *n != 30)
+            .collect_signal();
+        assert_eq!(labels.get(), vec![20, 40]);
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = labels.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        list.push(5);
+        let _ = list.remove(1);
+        assert_eq!(*seen.borrow(), vec![vec![20, 40, 50], vec![40, 50]]);
+    }
+
+    #[test]
+    fn test_signal_iter_filter_map_drops_items() {
+        let list = List::from(vec!["1", "x", "3"]);
+        let numbers = list
+            .signal_iter()
+            .filter_map(|text| text.parse::<i32>().ok())
+            .collect_signal();
+        assert_eq!(numbers.get(), vec![1, 3]);
+
+        list.clear();
+        assert!(numbers.get().is_empty());
+    }
+
+    #[test]
+    fn test_sorted_view_resorts_without_touching_source() {
+        let list = List::from(vec![3, 1, 2]);
+        let descending = list.sorted_view(|a, b| b.cmp(a));
+        assert_eq!(descending.get(), vec![3, 2, 1]);
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = descending.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        list.push(5);
+        list.insert(0, 0);
+        let _ = list.remove(1);
+
+        assert_eq!(
+            *seen.borrow(),
+            vec![vec![5, 3, 2, 1], vec![5, 3, 2, 1, 0], vec![5, 2, 1, 0]]
+        );
+        assert_eq!(list.snapshot(), vec![0, 1, 2, 5]);
+    }
+
+    #[test]
+    fn test_index_of_signal_follows_item() {
+        let list = List::from(vec!["a", "b", "selected", "c"]);
+        let index = list.index_of_signal(|item| *item == "selected");
+        assert_eq!(index.get(), Some(2));
+
+        list.sort();
+        assert_eq!(list.snapshot(), vec!["a", "b", "c", "selected"]);
+        assert_eq!(index.get(), Some(3));
+
+        assert_eq!(list.remove(3), "selected");
+        assert_eq!(index.get(), None);
+    }
+
+    #[test]
+    fn test_find_map_signal_tracks_matching_element() {
+        let list = List::from(vec!["apple", "kiwi"]);
+        let first_long = list.find_map_signal(|item| (item.len() > 5).then_some(item.len()));
+        assert_eq!(first_long.get(), None);
+
+        list.push("banana");
+        assert_eq!(first_long.get(), Some(6));
+
+        list.insert(0, "cherimoya");
+        assert_eq!(first_long.get(), Some(9));
+
+        list.retain(|item| item.len() <= 5);
+        assert_eq!(first_long.get(), None);
+    }
+
+    #[test]
+    fn test_partition_signal_updates_both_halves() {
+        let list = List::from(vec![1, 2, 3, 4]);
+        let halves = list.partition_signal(|n| n % 2 == 0);
+        assert_eq!(halves.get(), (vec![2, 4], vec![1, 3]));
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = halves.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        list.push(6);
+        list.retain(|n| *n != 1);
+        assert_eq!(
+            *seen.borrow(),
+            vec![(vec![2, 4, 6], vec![1, 3]), (vec![2, 4, 6], vec![3]),]
+        );
+    }
+
+    #[test]
+    fn test_chunks_signal_rechunks_after_mutation() {
+        let list = List::from((1..=7).collect::<Vec<_>>());
+        let rows = list.chunks_signal(3);
+        assert_eq!(rows.get(), vec![vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = rows.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        list.push(8);
+        let _ = list.remove(0);
+
+        assert_eq!(
+            *seen.borrow(),
+            vec![
+                vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8]],
+                vec![vec![2, 3, 4], vec![5, 6, 7], vec![8]],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_windows_signal_supports_moving_average() {
+        let samples = List::from(vec![1.0, 2.0]);
+        let windows = samples.windows_signal(3);
+        assert!(windows.get().is_empty(), "too few items for a window");
+
+        samples.push(3.0);
+        samples.push(5.0);
+        assert_eq!(
+            windows.get(),
+            vec![vec![1.0, 2.0, 3.0], vec![2.0, 3.0, 5.0]]
+        );
+
+        let averages = windows.map(|windows: Vec<Vec<f64>>| {
+            windows
+                .iter()
+                .map(|w| w.iter().sum::<f64>() / 3.0)
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(averages.get(), vec![2.0, 10.0 / 3.0]);
+    }
+
+    #[test]
+    fn test_join_signal_updates_on_push_and_remove() {
+        let crumbs: List<String> = List::from(vec![String::from("home"), String::from("docs")]);
+        let path = crumbs.join_signal(" / ");
+        assert_eq!(path.get(), "home / docs");
+
+        crumbs.push(String::from("api"));
+        assert_eq!(path.get(), "home / docs / api");
+
+        let _ = crumbs.remove(0);
+        assert_eq!(path.get(), "docs / api");
+
+        crumbs.clear();
+        assert_eq!(path.get(), "");
+    }
+
+    #[test]
+    fn test_list_all_true_and_any_true() {
+        let flags = List::from(vec![true, false, true]);
+        let all = flags.all_true();
+        let any = flags.any_true();
+        assert!(!all.get());
+        assert!(any.get());
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = all.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        flags.set_item(1, true);
+        assert!(all.get());
+        flags.set_item(0, false);
+        assert_eq!(*seen.borrow(), vec![true, false]);
+
+        flags.set_item(0, true);
+        flags.set_item(1, false);
+        flags.set_item(2, false);
+        flags.set_item(0, false);
+        assert!(!any.get());
+    }
+
+    #[test]
+    fn test_list_boolean_reduction_of_empty_list() {
+        let flags: List<bool> = List::new();
+        let all = flags.all_true();
+        let any = flags.any_true();
+        assert!(all.get());
+        assert!(!any.get());
+
+        flags.push(false);
+        assert!(!all.get());
+        flags.clear();
+        assert!(all.get());
+        assert!(!any.get());
+    }
+
+    #[test]
+    fn test_page_slice_follows_page_and_list() {
+        let list = List::from(vec![1, 2, 3, 4, 5]);
+        let page: crate::Binding<usize> = crate::binding(0_usize);
+        let visible = page_slice(list.clone(), page.clone(), 2);
+        assert_eq!(visible.get(), vec![1, 2]);
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = visible.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        page.set(1);
+        list.insert(0, 0);
+        page.set(2);
+        page.set(9);
+
+        assert_eq!(
+            *seen.borrow(),
+            vec![vec![3, 4], vec![2, 3], vec![4, 5], vec![]]
+        );
+    }
+
     #[test]
     fn test_vec_collection_implementation() {
         let vec = vec![1, 2, 3, 4, 5];
@@ -696,6 +1994,36 @@ mod tests {
         assert_eq!(called.get(), Some(true));
     }
 
+    #[test]
+    fn test_frame_coalesced_notifies_once_per_tick() {
+        use crate::{Binding, binding};
+
+        let list = List::from(vec![1]);
+        let frame: Binding<u64> = binding(0_u64);
+        let coalesced = list.frame_coalesced(frame.clone());
+        let calls: Rc<RefCell<Vec<Vec<i32>>>> = Rc::default();
+        let calls_clone = calls.clone();
+        let _guard = Collection::watch(&coalesced, .., move |ctx| {
+            calls_clone.borrow_mut().push(ctx.value().to_vec());
+        });
+        calls.borrow_mut().clear();
+
+        list.push(2);
+        list.push(3);
+        assert_eq!(list.remove(0), 1);
+        assert!(
+            calls.borrow().is_empty(),
+            "mutations wait for the next frame"
+        );
+
+        frame.set(1);
+        frame.set(2);
+        list.push(4);
+        frame.set(3);
+
+        assert_eq!(*calls.borrow(), vec![vec![2, 3], vec![2, 3, 4]]);
+    }
+
     #[test]
     fn test_watcher_guard_cleanup() {
         let list = List::new();
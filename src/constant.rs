@@ -63,6 +63,13 @@ impl<T> From<T> for Constant<T> {
     }
 }
 
+/// Formats the constant value.
+impl<T: core::fmt::Display> core::fmt::Display for Constant<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.0, f)
+    }
+}
+
 impl<T: Clone + 'static> Signal for Constant<T> {
     type Output = T;
     type Guard = ();
@@ -1,20 +1,28 @@
 //! Debounce utilities for throttling signal updates.
 //!
-//! This module provides (or will provide) helpers to debounce and throttle
-//! reactive updates. It is currently a placeholder.
+//! [`Debounce::new`] covers the common trailing-edge case; [`Debounce::builder`]
+//! configures the leading edge, a maximum wait, de-duplication, and the executor.
 use alloc::{boxed::Box, rc::Rc};
-use core::{cell::RefCell, fmt::Debug, time::Duration};
+use core::{
+    cell::{Cell, RefCell},
+    fmt::Debug,
+    time::Duration,
+};
 use executor_core::{DefaultExecutor, LocalExecutor, Task};
 use nami_core::watcher::Context;
+use std::time::Instant;
 
 use crate::{
     Signal,
-    utils::sleep,
+    utils::TimeSource,
     watcher::{WatcherManager, WatcherManagerGuard},
 };
 
 /// A debounce wrapper that delays signal updates until a specified duration has passed
 /// without new updates. This helps reduce the frequency of updates for rapidly changing signals.
+///
+/// Clones share the timer, downstream watchers, and upstream subscription. Whichever
+/// clone is watched first establishes the subscription, so clones may be watched in any order.
 pub struct Debounce<S, E>
 where
     S: Signal,
@@ -25,6 +33,33 @@ where
     executor: E,
     timer: Rc<RefCell<Option<Box<dyn Task<()>>>>>,
     guard: Rc<RefCell<Option<S::Guard>>>,
+    leading: bool,
+    max_wait: Option<Duration>,
+    /// When the current burst of updates started, if one is in progress.
+    burst: Rc<Cell<Option<Instant>>>,
+    /// Equality used to skip repeated values before they touch the timer.
+    distinct: Option<EqFn<S::Output>>,
+    last: Rc<RefCell<LastValues<S::Output>>>,
+}
+
+/// Equality used by a de-duplicating debounce.
+type EqFn<T> = fn(&T, &T) -> bool;
+
+/// The values a de-duplicating debounce compares incoming updates against.
+struct LastValues<T> {
+    /// The most recent upstream value.
+    seen: Option<T>,
+    /// The most recent value delivered to watchers.
+    delivered: Option<T>,
+}
+
+impl<T> Default for LastValues<T> {
+    fn default() -> Self {
+        Self {
+            seen: None,
+            delivered: None,
+        }
+    }
 }
 
 impl<S, E> Debug for Debounce<S, E>
@@ -40,7 +75,10 @@ where
             .field("executor", &self.executor)
             .field("timer", &"<...>")
             .field("guard", &"<...>")
-            .finish()
+            .field("leading", &self.leading)
+            .field("max_wait", &self.max_wait)
+            .field("distinct", &self.distinct.is_some())
+            .finish_non_exhaustive()
     }
 }
 
@@ -57,6 +95,11 @@ where
             executor: self.executor.clone(),
             timer: self.timer.clone(),
             guard: self.guard.clone(),
+            leading: self.leading,
+            max_wait: self.max_wait,
+            burst: self.burst.clone(),
+            distinct: self.distinct,
+            last: self.last.clone(),
         }
     }
 }
@@ -75,6 +118,11 @@ where
             executor,
             timer: Rc::default(),
             guard: Rc::default(),
+            leading: false,
+            max_wait: None,
+            burst: Rc::default(),
+            distinct: None,
+            last: Rc::default(),
         }
     }
 }
@@ -91,6 +139,144 @@ where
     {
         Self::with_executor(signal, duration, executor_core::DefaultExecutor)
     }
+
+    /// Starts configuring a debounce wrapper around `signal`.
+    ///
+    /// ```rust
+    /// use core::time::Duration;
+    /// use nami::{Binding, binding};
+    /// use nami::debounce::Debounce;
+    ///
+    /// let query: Binding<String> = binding(String::new());
+    /// let search = Debounce::builder(query)
+    ///     .duration(Duration::from_millis(300))
+    ///     .leading(true)
+    ///     .max_wait(Duration::from_secs(1))
+    ///     .distinct()
+    ///     .build();
+    /// # drop(search);
+    /// ```
+    pub const fn builder(signal: S) -> DebounceBuilder<S, DefaultExecutor> {
+        DebounceBuilder {
+            signal,
+            duration: Duration::ZERO,
+            leading: false,
+            max_wait: None,
+            distinct: None,
+            executor: DefaultExecutor,
+        }
+    }
+}
+
+/// A fluent builder for [`Debounce`], created by [`Debounce::builder`].
+pub struct DebounceBuilder<S: Signal, E> {
+    signal: S,
+    duration: Duration,
+    leading: bool,
+    max_wait: Option<Duration>,
+    distinct: Option<EqFn<S::Output>>,
+    executor: E,
+}
+
+impl<S, E> Debug for DebounceBuilder<S, E>
+where
+    S: Signal + Debug,
+    E: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DebounceBuilder")
+            .field("signal", &self.signal)
+            .field("duration", &self.duration)
+            .field("leading", &self.leading)
+            .field("max_wait", &self.max_wait)
+            .field("distinct", &self.distinct.is_some())
+            .field("executor", &self.executor)
+            .finish()
+    }
+}
+
+impl<S: Signal, E: Clone> Clone for DebounceBuilder<S, E> {
+    fn clone(&self) -> Self {
+        Self {
+            signal: self.signal.clone(),
+            duration: self.duration,
+            leading: self.leading,
+            max_wait: self.max_wait,
+            distinct: self.distinct,
+            executor: self.executor.clone(),
+        }
+    }
+}
+
+impl<S, E> DebounceBuilder<S, E>
+where
+    S: Signal,
+    E: LocalExecutor + Clone + 'static,
+{
+    /// Sets how long the source must stay quiet before the last value is delivered.
+    ///
+    /// Defaults to zero, which delivers on the executor's next turn.
+    #[must_use]
+    pub const fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Also delivers the first value of each burst immediately.
+    ///
+    /// The last value of the burst is still delivered after the quiet period,
+    /// unless the leading value was the only one.
+    #[must_use]
+    pub const fn leading(mut self, leading: bool) -> Self {
+        self.leading = leading;
+        self
+    }
+
+    /// Delivers the latest value at most `max_wait` after a burst starts, even if
+    /// the source never goes quiet.
+    #[must_use]
+    pub const fn max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = Some(max_wait);
+        self
+    }
+
+    /// Ignores updates equal to the previous one before they reach the timer.
+    ///
+    /// A repeated value neither restarts the quiet period nor counts as a new
+    /// burst. An update that returns to the last delivered value (e.g. typing a
+    /// character and deleting it again) cancels the pending delivery instead.
+    #[must_use]
+    pub fn distinct(mut self) -> Self
+    where
+        S::Output: PartialEq,
+    {
+        self.distinct = Some(PartialEq::eq);
+        self
+    }
+
+    /// Runs the debounce timers on `executor`.
+    pub fn executor<E2>(self, executor: E2) -> DebounceBuilder<S, E2>
+    where
+        E2: LocalExecutor + Clone + 'static,
+    {
+        DebounceBuilder {
+            signal: self.signal,
+            duration: self.duration,
+            leading: self.leading,
+            max_wait: self.max_wait,
+            distinct: self.distinct,
+            executor,
+        }
+    }
+
+    /// Builds the configured debounce wrapper.
+    pub fn build(self) -> Debounce<S, E> {
+        let mut debounce = Debounce::with_executor(self.signal, self.duration, self.executor);
+        debounce.leading = self.leading;
+        debounce.max_wait = self.max_wait;
+        debounce.distinct = self.distinct;
+        debounce
+    }
 }
 
 impl<S, E> Signal for Debounce<S, E>
@@ -111,29 +297,86 @@ where
         let watchers = self.watchers.clone();
         let executor = self.executor.clone();
         let timer = self.timer.clone();
+        let burst = self.burst.clone();
         let duration = self.duration;
+        let leading = self.leading;
+        let max_wait = self.max_wait;
+        let distinct = self.distinct;
+        let last = self.last.clone();
 
         // Ensure we only set up the upstream watcher once
         let _signal_guard = self.guard.borrow_mut().get_or_insert_with(|| {
+            if distinct.is_some() {
+                let current = signal.get();
+                *last.borrow_mut() = LastValues {
+                    seen: Some(current.clone()),
+                    delivered: Some(current),
+                };
+            }
             signal.watch(move |ctx| {
+                if let Some(eq) = distinct {
+                    let mut values = last.borrow_mut();
+                    if values
+                        .seen
+                        .as_ref()
+                        .is_some_and(|seen| eq(seen, ctx.value()))
+                    {
+                        return;
+                    }
+                    values.seen = Some(ctx.value().clone());
+                    if values
+                        .delivered
+                        .as_ref()
+                        .is_some_and(|delivered| eq(delivered, ctx.value()))
+                    {
+                        // Back where watchers already are: drop the pending delivery
+                        drop(values);
+                        let _previous_task = timer.borrow_mut().take();
+                        burst.set(None);
+                        return;
+                    }
+                }
+
                 // Cancel any existing timer by dropping the previous task
                 let _previous_task = timer.borrow_mut().take();
 
                 let watchers = watchers.clone();
-                let timer = timer.clone();
+                let burst = burst.clone();
+                let last = last.clone();
 
                 if watchers.is_empty() {
                     return;
                 }
 
-                let delayed_context = ctx;
+                let time = TimeSource::of(&executor);
+                let now = time.now();
+                let started = burst.get().unwrap_or(now);
+                let pending = if leading && burst.get().is_none() {
+                    if distinct.is_some() {
+                        last.borrow_mut().delivered = Some(ctx.value().clone());
+                    }
+                    watchers.notify(&ctx);
+                    None
+                } else {
+                    Some(ctx)
+                };
+                burst.set(Some(started));
+
+                let delay = max_wait.map_or(duration, |max_wait| {
+                    duration.min(max_wait.saturating_sub(now - started))
+                });
                 let task = executor.spawn_local(async move {
-                    sleep(duration).await;
+                    time.sleep(delay).await;
+                    burst.set(None);
                     if watchers.is_empty() {
                         return;
                     }
-                    let context = delayed_context;
-                    watchers.notify(&context);
+                    if let Some(context) = pending {
+                        if distinct.is_some() {
+                            last.borrow_mut().delivered = Some(context.value().clone());
+                        }
+                        watchers.notify(&context);
+                    }
                 });
 
                 *timer.borrow_mut() = Some(Box::new(task));
@@ -143,3 +386,185 @@ where
         self.watchers.register_as_guard(watcher)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Binding, binding, testing::ManualTimer};
+    use alloc::{vec, vec::Vec};
+
+    const DELAY: Duration = Duration::from_millis(20);
+
+    fn record(signal: &impl Signal<Output = i32>) -> (Rc<RefCell<Vec<i32>>>, impl Sized) {
+        let seen: Rc<RefCell<Vec<i32>>> = Rc::default();
+        let seen_clone = seen.clone();
+        let guard = signal.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+        (seen, guard)
+    }
+
+    #[test]
+    fn debounce_delivers_last_value() {
+        let timer = ManualTimer::new();
+        let source: Binding<i32> = binding(0);
+        let debounced = Debounce::with_executor(source.clone(), DELAY, timer.clone());
+        let (seen, _guard) = record(&debounced);
+
+        source.set(1);
+        source.set(2);
+        source.set(3);
+        timer.advance(DELAY * 3);
+
+        assert_eq!(*seen.borrow(), vec![3]);
+    }
+
+    #[test]
+    fn builder_defaults_match_with_executor() {
+        let timer = ManualTimer::new();
+        let source: Binding<i32> = binding(0);
+        let debounced = Debounce::builder(source.clone())
+            .duration(DELAY)
+            .executor(timer.clone())
+            .build();
+        let (seen, _guard) = record(&debounced);
+
+        source.set(1);
+        source.set(2);
+        assert!(seen.borrow().is_empty());
+        timer.advance(DELAY * 3);
+
+        assert_eq!(*seen.borrow(), vec![2]);
+    }
+
+    #[test]
+    fn builder_leading_emits_first_and_last() {
+        let timer = ManualTimer::new();
+        let source: Binding<i32> = binding(0);
+        let debounced = Debounce::builder(source.clone())
+            .duration(DELAY)
+            .leading(true)
+            .executor(timer.clone())
+            .build();
+        let (seen, _guard) = record(&debounced);
+
+        source.set(1);
+        assert_eq!(*seen.borrow(), vec![1]);
+        source.set(2);
+        source.set(3);
+        timer.advance(DELAY * 3);
+        assert_eq!(*seen.borrow(), vec![1, 3]);
+
+        source.set(4);
+        timer.advance(DELAY * 3);
+        assert_eq!(
+            *seen.borrow(),
+            vec![1, 3, 4],
+            "a lone update only fires on the leading edge"
+        );
+    }
+
+    #[test]
+    fn builder_max_wait_fires_during_continuous_updates() {
+        let timer = ManualTimer::new();
+        let source: Binding<i32> = binding(0);
+        let step = Duration::from_millis(30);
+        let debounced = Debounce::builder(source.clone())
+            .duration(step * 2)
+            .max_wait(Duration::from_millis(100))
+            .executor(timer.clone())
+            .build();
+        let (seen, _guard) = record(&debounced);
+
+        for value in 1..=4 {
+            source.set(value);
+            timer.advance(step);
+        }
+        // The quiet period alone would not end until 60ms after the last update.
+        assert_eq!(*seen.borrow(), vec![4]);
+    }
+
+    #[test]
+    fn distinct_type_then_undo_does_not_fire() {
+        let timer = ManualTimer::new();
+        let source: Binding<i32> = binding(1);
+        let debounced = Debounce::builder(source.clone())
+            .duration(DELAY)
+            .distinct()
+            .executor(timer.clone())
+            .build();
+        let (seen, _guard) = record(&debounced);
+
+        source.set(12);
+        source.set(1);
+        timer.advance(DELAY * 3);
+        assert!(seen.borrow().is_empty());
+
+        source.set(2);
+        timer.advance(DELAY * 3);
+        assert_eq!(*seen.borrow(), vec![2]);
+    }
+
+    #[test]
+    fn distinct_repeat_does_not_restart_timer() {
+        let timer = ManualTimer::new();
+        let source: Binding<i32> = binding(0);
+        let debounced = Debounce::builder(source.clone())
+            .duration(DELAY)
+            .distinct()
+            .executor(timer.clone())
+            .build();
+        let (seen, _guard) = record(&debounced);
+
+        source.set(5);
+        timer.advance(DELAY / 2);
+        source.set(5);
+        timer.advance(DELAY * 3 / 4);
+        assert_eq!(
+            *seen.borrow(),
+            vec![5],
+            "the repeated value should not restart the quiet period"
+        );
+    }
+
+    #[test]
+    fn distinct_leading_fires_first_new_value_immediately() {
+        let timer = ManualTimer::new();
+        let source: Binding<i32> = binding(0);
+        let debounced = Debounce::builder(source.clone())
+            .duration(DELAY)
+            .leading(true)
+            .distinct()
+            .executor(timer.clone())
+            .build();
+        let (seen, _guard) = record(&debounced);
+
+        source.set(5);
+        assert_eq!(*seen.borrow(), vec![5]);
+        timer.advance(DELAY * 3);
+        source.set(5);
+        assert_eq!(*seen.borrow(), vec![5], "a repeat does not start a burst");
+
+        source.set(6);
+        source.set(6);
+        source.set(7);
+        assert_eq!(*seen.borrow(), vec![5, 6]);
+        timer.advance(DELAY * 3);
+        assert_eq!(*seen.borrow(), vec![5, 6, 7]);
+    }
+
+    #[test]
+    fn clone_watched_before_original_shares_subscription() {
+        let timer = ManualTimer::new();
+        let source: Binding<i32> = binding(0);
+        let original = Debounce::with_executor(source.clone(), DELAY, timer.clone());
+        let clone = original.clone();
+
+        let (from_clone, _clone_guard) = record(&clone);
+        let (from_original, _original_guard) = record(&original);
+
+        source.set(7);
+        timer.advance(DELAY * 3);
+
+        assert_eq!(*from_clone.borrow(), vec![7]);
+        assert_eq!(*from_original.borrow(), vec![7]);
+    }
+}
@@ -223,3 +223,136 @@ where
         guard
     }
 }
+
+/// A diagnostics wrapper that warns when a signal updates too often.
+///
+/// Notifications of the source are counted per time window. When more than
+/// `threshold` notifications arrive within one window, a warning is logged via
+/// `log::warn!` (once per window). This helps catch reactive storms such as
+/// feedback loops during development.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct WatchStorm<C> {
+    source: C,
+    inner: Rc<StormInner>,
+}
+
+#[cfg(feature = "std")]
+struct StormInner {
+    _guard: BoxWatcherGuard,
+    storms: Rc<core::cell::Cell<usize>>,
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Debug for StormInner {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct(type_name::<Self>())
+            .field("_guard", &"<opaque guard>")
+            .field("storms", &self.storms.get())
+            .finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: Signal> WatchStorm<C> {
+    /// The window used by [`WatchStorm::new`].
+    pub const DEFAULT_WINDOW: core::time::Duration = core::time::Duration::from_secs(1);
+
+    /// Creates a storm detector using a one-second window.
+    pub fn new(source: C, threshold: usize) -> Self {
+        Self::with_window(source, threshold, Self::DEFAULT_WINDOW)
+    }
+
+    /// Creates a storm detector with a custom window.
+    pub fn with_window(source: C, threshold: usize, window: core::time::Duration) -> Self {
+        use core::cell::Cell;
+        use std::time::Instant;
+
+        let storms = Rc::new(Cell::new(0));
+        let window_start = Cell::new(Instant::now());
+        let count = Cell::new(0_usize);
+        let name = type_name::<C>();
+
+        let guard = {
+            let storms = storms.clone();
+            source.watch(move |_| {
+                let now = Instant::now();
+                if now.duration_since(window_start.get()) > window {
+                    window_start.set(now);
+                    count.set(0);
+                }
+                count.set(count.get() + 1);
+                if count.get() == threshold + 1 {
+                    storms.set(storms.get() + 1);
+                    log::warn!(
+                        "`{name}` updated more than {threshold} times within {window:?}; possible reactive storm"
+                    );
+                }
+            })
+        };
+
+        Self {
+            source,
+            inner: Rc::new(StormInner {
+                _guard: Box::new(guard),
+                storms,
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C> WatchStorm<C> {
+    /// Returns how many windows exceeded the threshold so far.
+    #[must_use]
+    pub fn storms(&self) -> usize {
+        self.inner.storms.get()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: Signal> Signal for WatchStorm<C> {
+    type Output = C::Output;
+    type Guard = C::Guard;
+
+    fn get(&self) -> Self::Output {
+        self.source.get()
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.source.watch(watcher)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{Binding, SignalExt, binding};
+
+    #[test]
+    fn rapid_updates_trigger_storm_warning() {
+        let source: Binding<i32> = binding(0);
+        let watched = source.watch_storm(3);
+
+        for value in 1..=3 {
+            source.set(value);
+        }
+        assert_eq!(watched.storms(), 0);
+
+        source.set(4);
+        source.set(5);
+        assert_eq!(watched.storms(), 1, "warns once per window");
+        assert_eq!(watched.get(), 5);
+    }
+
+    #[test]
+    fn counts_reset_after_window() {
+        let source: Binding<i32> = binding(0);
+        let watched = WatchStorm::with_window(source.clone(), 1, core::time::Duration::ZERO);
+
+        source.set(1);
+        std::thread::sleep(core::time::Duration::from_millis(1));
+        source.set(2);
+        assert_eq!(watched.storms(), 0);
+    }
+}
@@ -6,19 +6,20 @@
 
 use core::cell::RefCell;
 
-use alloc::rc::Rc;
 use nami_core::watcher::Context;
 
 use crate::signal::Signal;
 
 /// A distinct signal that only notifies on value changes.
+///
+/// Each watcher compares against the value it last saw, starting from the
+/// value at the time it was registered.
 #[derive(Debug, Clone)]
 pub struct Distinct<S: Signal>
 where
     S::Output: PartialEq,
 {
     signal: S,
-    last_value: Rc<RefCell<Option<S::Output>>>,
 }
 
 impl<S: Signal> Distinct<S>
@@ -26,11 +27,8 @@ where
     S::Output: PartialEq,
 {
     /// Creates a new distinct signal from the given signal.
-    pub fn new(signal: S) -> Self {
-        Self {
-            signal,
-            last_value: Rc::new(RefCell::new(None)),
-        }
+    pub const fn new(signal: S) -> Self {
+        Self { signal }
     }
 }
 
@@ -46,22 +44,81 @@ where
     }
 
     fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
-        let last_value_store = self.last_value.clone();
+        let last_value = RefCell::new(self.signal.get());
         self.signal.watch(move |ctx: Context<S::Output>| {
-            let last_value = last_value_store.borrow();
-            if let Some(last_value) = &*last_value {
-                if last_value != ctx.value() {
-                    *last_value_store.borrow_mut() = Some(ctx.value().clone());
-                    watcher(ctx);
-                }
-            } else {
-                // First time watching, set the last value
-                *last_value_store.borrow_mut() = Some(ctx.value().clone());
-                watcher(ctx);
+            let mut last = last_value.borrow_mut();
+            if *last == *ctx.value() {
+                return;
             }
+            *last = ctx.value().clone();
+            // Release the borrow so the watcher may trigger nested notifications.
+            drop(last);
+            watcher(ctx);
         })
     }
 }
 
 // Note: Distinct<S> has an additional PartialEq bound, making it incompatible
 // with the generic wrapper macros. Users can convert to Computed for operators.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Binding, binding};
+    use alloc::{rc::Rc, vec, vec::Vec};
+
+    #[test]
+    fn distinct_skips_repeated_values() {
+        let source: Binding<i32> = binding(1);
+        let distinct = Distinct::new(source.clone());
+
+        let seen: Rc<RefCell<Vec<i32>>> = Rc::default();
+        let seen_clone = seen.clone();
+        let _guard = distinct.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        for value in [1, 2, 2, 3, 3, 2] {
+            source.set(value);
+        }
+
+        assert_eq!(*seen.borrow(), vec![2, 3, 2]);
+    }
+
+    #[test]
+    fn watcher_may_update_the_source() {
+        let source: Binding<i32> = binding(0);
+        let distinct = Distinct::new(source.clone());
+
+        let seen: Rc<RefCell<Vec<i32>>> = Rc::default();
+        let _guard = {
+            let seen = seen.clone();
+            let source = source.clone();
+            distinct.watch(move |ctx| {
+                let value = ctx.into_value();
+                seen.borrow_mut().push(value);
+                // Clamping re-enters the distinct watcher while it is running.
+                if value > 10 {
+                    source.set(10);
+                }
+            })
+        };
+
+        source.set(15);
+        assert_eq!(*seen.borrow(), vec![15, 10]);
+    }
+
+    #[test]
+    fn each_watcher_starts_from_the_value_at_registration() {
+        let source: Binding<i32> = binding(1);
+        let distinct = Distinct::new(source.clone());
+        let _first = distinct.watch(|_| {});
+
+        source.set(2);
+        let seen: Rc<RefCell<Vec<i32>>> = Rc::default();
+        let seen_clone = seen.clone();
+        let _second = distinct.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        source.set(2);
+        source.set(3);
+        assert_eq!(*seen.borrow(), vec![3]);
+    }
+}
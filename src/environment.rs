@@ -0,0 +1,170 @@
+//! # Reactive Environment
+//!
+//! This module provides [`Environment`], a store for ambient values such as a
+//! theme or locale that many parts of a UI tree depend on. Values are keyed by
+//! their type, and lookups return signals, so dependents update whenever the
+//! ambient value is replaced.
+//!
+//! ```rust
+//! use nami::{Signal, SignalExt};
+//! use nami::environment::Environment;
+//!
+//! #[derive(Clone, Debug, PartialEq)]
+//! struct Theme(&'static str);
+//!
+//! let env = Environment::new();
+//! env.insert(Theme("light"));
+//!
+//! let name = env.get::<Theme>().unwrap().map(|theme| theme.0);
+//! assert_eq!(name.get(), "light");
+//!
+//! env.insert(Theme("dark"));
+//! assert_eq!(name.get(), "dark");
+//! ```
+
+use core::{
+    any::{Any, TypeId, type_name},
+    marker::PhantomData,
+};
+
+use alloc::rc::Rc;
+use nami_core::dictionary::{Dictionary, Map};
+
+use crate::{
+    Signal,
+    watcher::{Context, WatcherManagerGuard},
+};
+
+/// A reactive store of ambient values keyed by type.
+///
+/// Clones share the same underlying storage.
+#[derive(Debug, Clone, Default)]
+pub struct Environment {
+    values: Map<TypeId, Rc<dyn Any>>,
+}
+
+impl Environment {
+    /// Creates a new, empty environment.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts or replaces the ambient value of type `T`.
+    ///
+    /// Signals obtained from [`Environment::get`] for `T` are notified.
+    pub fn insert<T: Clone + 'static>(&self, value: T) {
+        self.values.insert(TypeId::of::<T>(), Rc::new(value));
+    }
+
+    /// Returns the current ambient value of type `T`, if any.
+    #[must_use]
+    pub fn value<T: Clone + 'static>(&self) -> Option<T> {
+        self.values
+            .get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref::<T>().cloned())
+    }
+
+    /// Returns a signal tracking the ambient value of type `T`.
+    ///
+    /// Returns `None` if no value of type `T` has been inserted yet.
+    #[must_use]
+    pub fn get<T: Clone + 'static>(&self) -> Option<EnvironmentValue<T>> {
+        self.values
+            .get(&TypeId::of::<T>())
+            .map(|_| EnvironmentValue {
+                environment: self.clone(),
+                _marker: PhantomData,
+            })
+    }
+}
+
+/// A signal tracking an ambient value of type `T` in an [`Environment`].
+#[derive(Debug)]
+pub struct EnvironmentValue<T> {
+    environment: Environment,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for EnvironmentValue<T> {
+    fn clone(&self) -> Self {
+        Self {
+            environment: self.environment.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone + 'static> Signal for EnvironmentValue<T> {
+    type Output = T;
+    type Guard = WatcherManagerGuard<Option<Rc<dyn Any>>>;
+
+    fn get(&self) -> Self::Output {
+        self.environment.value().unwrap_or_else(|| {
+            panic!(
+                "environment value of type `{}` is missing",
+                type_name::<T>()
+            )
+        })
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.environment.values.watch(
+            &TypeId::of::<T>(),
+            move |ctx: Context<Option<Rc<dyn Any>>>| {
+                let Some(value) = ctx
+                    .value()
+                    .as_ref()
+                    .and_then(|value| value.downcast_ref::<T>().cloned())
+                else {
+                    return;
+                };
+                watcher(ctx.map(|_| value));
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SignalExt;
+    use alloc::{vec, vec::Vec};
+    use core::cell::RefCell;
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Theme {
+        Light,
+        Dark,
+    }
+
+    #[test]
+    fn missing_value_has_no_signal() {
+        let env = Environment::new();
+        assert!(env.get::<Theme>().is_none());
+        assert_eq!(env.value::<Theme>(), None);
+    }
+
+    #[test]
+    fn dependent_signal_follows_theme() {
+        let env = Environment::new();
+        env.insert(Theme::Light);
+        env.insert(16_u32);
+
+        let is_dark = env
+            .get::<Theme>()
+            .unwrap()
+            .map(|theme| theme == Theme::Dark);
+        let seen: Rc<RefCell<Vec<bool>>> = Rc::default();
+        let seen_clone = seen.clone();
+        let _guard = is_dark.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        assert!(!is_dark.get());
+        env.insert(Theme::Dark);
+        env.insert(18_u32);
+        env.insert(Theme::Light);
+
+        assert_eq!(*seen.borrow(), vec![true, false]);
+        assert_eq!(env.value::<u32>(), Some(18));
+    }
+}
@@ -0,0 +1,120 @@
+//! # Event Log
+//!
+//! A capped, reactive log of the values a signal has emitted.
+//! See [`SignalExt::log_into`](crate::SignalExt::log_into).
+
+use alloc::{collections::VecDeque, rc::Rc};
+use core::any::Any;
+
+use crate::{Binding, CustomBinding, Signal, binding, watcher::Context};
+
+/// A binding holding the most recent emissions of a source signal.
+///
+/// Each emission is appended to the back of the log; once the log holds more
+/// than `capacity` entries the oldest are evicted from the front. The log
+/// binding notifies once per source emission. The source subscription lives
+/// as long as any clone of the log.
+#[derive(Clone)]
+pub struct EventLog<T: 'static> {
+    log: Binding<VecDeque<T>>,
+    _guard: Rc<dyn Any>,
+}
+
+impl<T: 'static> core::fmt::Debug for EventLog<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EventLog").finish_non_exhaustive()
+    }
+}
+
+impl<T: Clone + 'static> EventLog<T> {
+    /// Starts logging the emissions of `source`, keeping at most `capacity` entries.
+    pub fn new<S>(source: &S, capacity: usize) -> Self
+    where
+        S: Signal<Output = T>,
+    {
+        let log: Binding<VecDeque<T>> = binding(VecDeque::with_capacity(capacity));
+        let guard = {
+            let log = log.clone();
+            source.watch(move |ctx| {
+                log.with_mut(|entries| {
+                    entries.push_back(ctx.into_value());
+                    while entries.len() > capacity {
+                        entries.pop_front();
+                    }
+                });
+            })
+        };
+        Self {
+            log,
+            _guard: Rc::new(guard),
+        }
+    }
+}
+
+impl<T: Clone + 'static> Signal for EventLog<T> {
+    type Output = VecDeque<T>;
+    type Guard = <Binding<VecDeque<T>> as Signal>::Guard;
+
+    fn get(&self) -> Self::Output {
+        self.log.get()
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.log.watch(watcher)
+    }
+}
+
+impl<T: Clone + 'static> CustomBinding for EventLog<T> {
+    fn set(&self, value: Self::Output) {
+        self.log.set(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Binding, Signal, SignalExt, binding};
+    use alloc::{rc::Rc, vec, vec::Vec};
+    use core::cell::Cell;
+
+    #[test]
+    fn log_evicts_oldest_beyond_capacity() {
+        let source: Binding<i32> = binding(0);
+        let log = source.log_into(3);
+        assert!(log.get().is_empty());
+
+        for value in 1..=5 {
+            source.set(value);
+        }
+
+        assert_eq!(log.get().into_iter().collect::<Vec<_>>(), vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn log_notifies_once_per_emission() {
+        let source: Binding<i32> = binding(0);
+        let log = source.log_into(2);
+        let notifications = Rc::new(Cell::new(0));
+        let counter = notifications.clone();
+        let _guard = log.watch(move |_| counter.set(counter.get() + 1));
+
+        source.set(1);
+        source.set(2);
+        source.set(3);
+
+        assert_eq!(notifications.get(), 3);
+    }
+
+    #[test]
+    fn log_keeps_subscription_while_cloned() {
+        let source: Binding<i32> = binding(0);
+        let original = source.log_into(4);
+        let log = original.clone();
+        drop(original);
+
+        source.set(7);
+        log.set(log.get().into_iter().chain([8]).collect());
+        source.set(9);
+
+        assert_eq!(log.get().into_iter().collect::<Vec<_>>(), vec![7, 8, 9]);
+    }
+}
@@ -1,16 +1,81 @@
 #![allow(clippy::type_complexity)]
 
 use crate::{
-    Computed, Signal, cache::Cached, distinct::Distinct, map::Map, signal::WithMetadata, zip::Zip,
+    Computed, Signal,
+    active::ActiveWhen,
+    cache::Cached,
+    distinct::Distinct,
+    flat_map::FlatMap,
+    map::Map,
+    map_while::MapWhile,
+    pull::Pull,
+    sample::Sample,
+    scan::{MapStateful, ScanFilter},
+    signal::WithMetadata,
+    zip::Zip,
+};
+use alloc::{collections::BTreeMap, rc::Rc, string::String, vec::Vec};
+use core::{
+    cell::{Cell, RefCell},
+    str::FromStr,
+};
+use nami_core::watcher::{Context, Metadata, OnDrop, WatcherGuard};
+use num_traits::{
+    AsPrimitive, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, SaturatingAdd, SaturatingMul,
+    SaturatingSub, Signed, Zero, float::FloatCore,
 };
-use alloc::string::String;
-use num_traits::{Signed, Zero};
 
 #[cfg(feature = "timer")]
 use crate::debounce::Debounce;
-#[cfg(feature = "timer")]
 use core::time::Duration;
 
+/// The number of results kept by [`SignalExt::memoize_by`].
+const MEMOIZE_CAPACITY: usize = 16;
+
+/// A least-recently-used cache backing [`SignalExt::memoize_by`].
+struct LruCache<K, U> {
+    entries: BTreeMap<K, (U, u64)>,
+    clock: u64,
+}
+
+impl<K, U> Default for LruCache<K, U> {
+    fn default() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+            clock: 0,
+        }
+    }
+}
+
+impl<K: Ord + Clone, U: Clone> LruCache<K, U> {
+    const fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+
+    fn get(&mut self, key: &K) -> Option<U> {
+        let now = self.tick();
+        let (value, used) = self.entries.get_mut(key)?;
+        *used = now;
+        Some(value.clone())
+    }
+
+    fn insert(&mut self, key: K, value: U) {
+        if self.entries.len() >= MEMOIZE_CAPACITY {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        let now = self.tick();
+        self.entries.insert(key, (value, now));
+    }
+}
+
 /// Extension trait providing convenient methods for all Signal types.
 ///
 /// This trait adds utility methods to any type implementing Signal,
@@ -27,6 +92,18 @@ pub trait SignalExt: Signal {
         Map::new(self.clone(), f)
     }
 
+    /// Transforms a borrow of this signal's output, avoiding a clone where possible.
+    ///
+    /// Reads of container-backed bindings borrow the stored value; other
+    /// sources are still cloned. See [`MapRef`](crate::map::MapRef).
+    fn map_ref<F, Output>(&self, f: F) -> crate::map::MapRef<Self, F, Output>
+    where
+        F: 'static + Clone + Fn(&Self::Output) -> Output,
+        Output: 'static,
+    {
+        crate::map::MapRef::new(self.clone(), f)
+    }
+
     /// Combines this signal with another signal into a tuple.
     fn zip<B>(&self, b: &B) -> Zip<Self, B>
     where
@@ -37,6 +114,36 @@ pub trait SignalExt: Signal {
         Zip::new(self.clone(), b.clone())
     }
 
+    /// Computes the difference between this signal and `other` with `f`.
+    ///
+    /// `f` receives this signal's value first, so `|old, new| new - old`
+    /// yields the change from this signal to `other`. The result updates
+    /// whenever either side changes.
+    ///
+    /// ```
+    /// use nami::{Binding, Signal, SignalExt, binding};
+    ///
+    /// let budget: Binding<i32> = binding(100);
+    /// let spent: Binding<i32> = binding(30);
+    /// let remaining = budget.diff_with(&spent, |budget, spent| budget - spent);
+    /// assert_eq!(remaining.get(), 70);
+    /// ```
+    fn diff_with<B, D, F>(
+        &self,
+        other: &B,
+        f: F,
+    ) -> Map<Zip<Self, B>, impl 'static + Clone + Fn((Self::Output, B::Output)) -> D, D>
+    where
+        Self: 'static,
+        B: Signal,
+        Self::Output: Clone,
+        B::Output: Clone,
+        D: 'static,
+        F: 'static + Clone + Fn(Self::Output, B::Output) -> D,
+    {
+        self.zip(other).map(move |(a, b)| f(a, b))
+    }
+
     /// Wraps this signal with caching to avoid redundant computations.
     fn cached(&self) -> Cached<Self>
     where
@@ -53,11 +160,115 @@ pub trait SignalExt: Signal {
         Computed::new(self.clone())
     }
 
+    /// Runs `cleanup` once the returned signal and all its clones are dropped.
+    fn on_drop_signal(
+        &self,
+        cleanup: impl FnOnce() + 'static,
+    ) -> crate::signal::OnDropSignal<Self> {
+        crate::signal::OnDropSignal::new(self.clone(), cleanup)
+    }
+
     /// Attaches metadata to this signal's watcher notifications.
     fn with<T>(&self, metadata: T) -> WithMetadata<Self, T> {
         WithMetadata::new(metadata, self.clone())
     }
 
+    /// Observes metadata of type `M` attached to this signal's notifications.
+    ///
+    /// Watchers receive `Some(metadata)` only for notifications carrying an `M`.
+    fn metadata_signal<M: Clone + 'static>(&self) -> crate::signal::MetadataSignal<Self, M> {
+        crate::signal::MetadataSignal::new(self.clone())
+    }
+
+    /// Returns an awaitable handle yielding each subsequent change of this signal.
+    ///
+    /// ```
+    /// use nami::{Binding, SignalExt, binding};
+    ///
+    /// # async fn run() {
+    /// let count: Binding<i32> = binding(0);
+    /// let mut changes = count.changes();
+    /// count.set(1);
+    /// while let Some(value) = changes.next().await {
+    ///     assert_eq!(value, 1);
+    ///     break;
+    /// }
+    /// # }
+    /// ```
+    fn changes(&self) -> crate::stream::Changes<Self::Output>
+    where
+        Self::Output: 'static,
+    {
+        crate::stream::Changes::new(self)
+    }
+
+    /// Collects this signal's emissions into a binding capped at `capacity` entries.
+    ///
+    /// The oldest entries are evicted first; see [`EventLog`](crate::event_log::EventLog).
+    fn log_into(
+        &self,
+        capacity: usize,
+    ) -> crate::Binding<alloc::collections::VecDeque<Self::Output>>
+    where
+        Self::Output: Clone,
+    {
+        crate::Binding::custom(crate::event_log::EventLog::new(self, capacity))
+    }
+
+    /// Mirrors this signal into a [`Cell`] seeded with the current value.
+    ///
+    /// The cell follows every change until the returned guard is dropped, so
+    /// hot paths can read the latest value with a plain [`Cell::get`].
+    fn mirror_cell(&self) -> (Rc<Cell<Self::Output>>, Self::Guard)
+    where
+        Self::Output: Copy + 'static,
+    {
+        let cell = Rc::new(Cell::new(self.get()));
+        let guard = {
+            let cell = cell.clone();
+            self.watch(move |ctx| cell.set(ctx.into_value()))
+        };
+        (cell, guard)
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    /// Records every value this signal emits, for asserting on reactive pipelines in tests.
+    ///
+    /// Recording stops when the returned guard is dropped.
+    ///
+    /// ```
+    /// use nami::{Binding, SignalExt, binding};
+    ///
+    /// let count: Binding<i32> = binding(0);
+    /// let (recorder, _guard) = count.map(|n| n * 10).record();
+    ///
+    /// count.set(1);
+    /// count.set(2);
+    /// assert_eq!(recorder.values(), vec![10, 20]);
+    /// ```
+    fn record(&self) -> (crate::testing::Recorder<Self::Output>, Self::Guard)
+    where
+        Self::Output: 'static,
+    {
+        crate::testing::Recorder::new(self)
+    }
+
+    #[cfg(feature = "std")]
+    /// Attaches the notification time as an [`Instant`](std::time::Instant) to each watcher context.
+    ///
+    /// This is a debugging aid for inspecting how often a signal updates.
+    fn timestamped(&self) -> crate::signal::Timestamped<Self> {
+        crate::signal::Timestamped::new(self.clone())
+    }
+
+    #[cfg(feature = "std")]
+    /// Logs a warning when this signal updates more than `threshold` times within one second.
+    ///
+    /// This is a diagnostics aid for reactive storms; see [`WatchStorm`](crate::debug::WatchStorm).
+    fn watch_storm(&self, threshold: usize) -> crate::debug::WatchStorm<Self> {
+        crate::debug::WatchStorm::new(self.clone(), threshold)
+    }
+
     // ==================== Map Variants ====================
 
     /// Transforms the output using `Into::into`.
@@ -70,6 +281,29 @@ pub trait SignalExt: Signal {
         self.map(Into::into)
     }
 
+    /// Flattens a signal of signals, following the inner signal `f` selects.
+    ///
+    /// Each time this signal changes, the previous inner signal is
+    /// unsubscribed and watchers receive the new inner signal's current value.
+    fn flat_map<S2, F>(&self, f: F) -> FlatMap<Self, F>
+    where
+        F: Fn(Self::Output) -> S2 + Clone + 'static,
+        S2: Signal,
+    {
+        FlatMap::new(self.clone(), f)
+    }
+
+    /// Follows whichever signal this signal currently holds.
+    ///
+    /// Equivalent to `flat_map` with the identity function.
+    fn switch<S2>(&self) -> FlatMap<Self, fn(S2) -> S2>
+    where
+        Self: Signal<Output = S2>,
+        S2: Signal,
+    {
+        FlatMap::new(self.clone(), core::convert::identity)
+    }
+
     /// Applies a side-effect function and returns the original value.
     fn inspect<F>(
         &self,
@@ -86,6 +320,115 @@ pub trait SignalExt: Signal {
         })
     }
 
+    /// Expands each value into several, collected into a `Vec`.
+    ///
+    /// A signal holds one current value, so the expansion of each input is
+    /// emitted as a single `Vec<U>` rather than as separate notifications.
+    fn flat_expand<U, I, F>(
+        &self,
+        f: F,
+    ) -> Map<Self, impl 'static + Clone + Fn(Self::Output) -> Vec<U>, Vec<U>>
+    where
+        Self: 'static,
+        U: 'static,
+        I: IntoIterator<Item = U>,
+        F: 'static + Clone + Fn(Self::Output) -> I,
+    {
+        Map::new(self.clone(), move |value| f(value).into_iter().collect())
+    }
+
+    /// Transforms the output with access to mutable state seeded from `init`.
+    ///
+    /// `f` runs once for the current value and once per change, and its result
+    /// is cached, so `get` and extra watchers do not advance the state. See
+    /// [`MapStateful`].
+    fn map_stateful<St, U, F>(&self, init: St, f: F) -> MapStateful<U>
+    where
+        St: 'static,
+        U: Clone + 'static,
+        F: 'static + Fn(&RefCell<St>, Self::Output) -> U,
+    {
+        MapStateful::new(self, init, f)
+    }
+
+    /// Transforms the output, caching results by `key` so repeated inputs skip `compute`.
+    ///
+    /// Up to 16 results are kept; beyond that the least
+    /// recently used entry is evicted. `compute` must be pure, since cached
+    /// results are reused for any input with the same key.
+    fn memoize_by<K, U, KF, F>(
+        &self,
+        key: KF,
+        compute: F,
+    ) -> Map<Self, impl 'static + Clone + Fn(Self::Output) -> U, U>
+    where
+        Self: 'static,
+        K: Ord + Clone + 'static,
+        U: Clone + 'static,
+        KF: 'static + Fn(&Self::Output) -> K,
+        F: 'static + Fn(Self::Output) -> U,
+    {
+        let memo = Rc::new((RefCell::new(LruCache::default()), key, compute));
+        Map::new(self.clone(), move |value| {
+            let (cache, key, compute) = &*memo;
+            let key = key(&value);
+            if let Some(hit) = cache.borrow_mut().get(&key) {
+                return hit;
+            }
+            let result = compute(value);
+            cache.borrow_mut().insert(key, result.clone());
+            result
+        })
+    }
+
+    #[cfg(feature = "std")]
+    /// Transforms the output, emitting `fallback` if the function panics.
+    ///
+    /// The panic is caught with [`std::panic::catch_unwind`] so it does not unwind
+    /// through the reactive propagation. The panic hook still runs as usual.
+    fn catch_map<F, Output>(
+        &self,
+        f: F,
+        fallback: Output,
+    ) -> Map<Self, impl 'static + Clone + Fn(Self::Output) -> Output, Output>
+    where
+        Self: 'static,
+        Self::Output: std::panic::UnwindSafe,
+        F: 'static + Clone + Fn(Self::Output) -> Output + std::panic::RefUnwindSafe,
+        Output: 'static + Clone,
+    {
+        Map::new(self.clone(), move |value| {
+            std::panic::catch_unwind(|| f(value)).unwrap_or_else(|_| fallback.clone())
+        })
+    }
+
+    /// Registers a watcher like [`Signal::watch`] and immediately calls it once
+    /// with the current value.
+    ///
+    /// The immediate call receives a context with empty metadata and runs
+    /// after registration, outside of any notification, so reading other
+    /// signals with `get()` inside the watcher cannot hit a borrowed `RefCell`.
+    fn watch_immediate(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        let watcher = Rc::new(watcher);
+        let guard = {
+            let watcher = watcher.clone();
+            self.watch(move |ctx| watcher(ctx))
+        };
+        watcher(Context::new(self.get(), Metadata::new()));
+        guard
+    }
+
+    /// Caches this signal and only notifies when its value actually changes.
+    ///
+    /// This combines [`cached`](Self::cached) and [`distinct`](Self::distinct)
+    /// behind a single subscription to this signal; see [`Memo`](crate::cache::Memo).
+    fn memo(&self) -> crate::cache::Memo<Self>
+    where
+        Self::Output: PartialEq + Clone,
+    {
+        crate::cache::Memo::new(self.clone())
+    }
+
     /// Creates a distinct signal that only notifies on value changes.
     fn distinct(&self) -> Distinct<Self>
     where
@@ -94,6 +437,77 @@ pub trait SignalExt: Signal {
         Distinct::new(self.clone())
     }
 
+    /// Reads this signal each time `trigger` notifies, ignoring its own changes.
+    fn sample<T: Signal>(&self, trigger: &T) -> Sample<Self, T> {
+        Sample::new(self.clone(), trigger.clone())
+    }
+
+    /// Creates a pull-based signal that recomputes on every `get()`.
+    ///
+    /// The returned signal never notifies watchers, making it suitable for
+    /// expensive values that should only be computed when read.
+    fn pull(&self) -> Pull<Self> {
+        Pull::new(self.clone())
+    }
+
+    /// Calls `f` with the next value this signal emits, then stops watching.
+    ///
+    /// Dropping the returned guard before the first emission cancels the call.
+    fn once(&self, f: impl FnOnce(Self::Output) + 'static) -> impl WatcherGuard {
+        let slot: Rc<RefCell<Option<Self::Guard>>> = Rc::default();
+        let callback = RefCell::new(Some(f));
+        let guard = {
+            let slot = slot.clone();
+            self.watch(move |ctx| {
+                let f = callback.borrow_mut().take();
+                if let Some(f) = f {
+                    let guard = slot.borrow_mut().take();
+                    drop(guard);
+                    f(ctx.into_value());
+                }
+            })
+        };
+        *slot.borrow_mut() = Some(guard);
+        OnDrop::new(move || drop(slot.borrow_mut().take()))
+    }
+
+    /// Folds every emission into an accumulator, notifying only when `step` returns `true`.
+    ///
+    /// The accumulator is always updated; the boolean returned by `step` only
+    /// controls whether watchers see this step (e.g. every Kth value, or when
+    /// crossing a threshold).
+    fn scan_filter<St, F>(&self, init: St, step: F) -> ScanFilter<St>
+    where
+        St: Clone + 'static,
+        F: Fn(&mut St, Self::Output) -> bool + 'static,
+    {
+        ScanFilter::new(self, init, step)
+    }
+
+    /// Forwards mapped values until `f` first returns `None`, then stops.
+    ///
+    /// On the first `None` the upstream subscription is dropped. `get()`
+    /// returns the last forwarded value.
+    fn map_while<U, F>(&self, f: F) -> MapWhile<U>
+    where
+        U: Clone + 'static,
+        F: Fn(Self::Output) -> Option<U> + 'static,
+    {
+        MapWhile::new(self, f)
+    }
+
+    /// Follows this signal only while `active` is `true`.
+    ///
+    /// While inactive, this signal is not subscribed to, so it neither
+    /// recomputes nor notifies; reactivating delivers its current value.
+    fn active_when<G>(&self, active: G) -> ActiveWhen<Self::Output>
+    where
+        G: Signal<Output = bool>,
+        Self::Output: Clone,
+    {
+        ActiveWhen::new(self, active)
+    }
+
     // ==================== Comparison Methods ====================
 
     /// Returns `true` if the value equals the given value.
@@ -277,6 +691,47 @@ pub trait SignalExt: Signal {
         Map::new(self.clone(), move |opt| opt.and_then(&f))
     }
 
+    /// Returns this signal's value if `Some`, otherwise the value of `other`.
+    ///
+    /// This is the reactive analog of [`Option::or`], updating when either signal changes.
+    fn or_signal<T, B>(
+        &self,
+        other: &B,
+    ) -> Map<Zip<Self, B>, fn((Option<T>, Option<T>)) -> Option<T>, Option<T>>
+    where
+        Self: Signal<Output = Option<T>> + 'static,
+        B: Signal<Output = Option<T>> + 'static,
+        T: Clone + 'static,
+    {
+        Zip::new(self.clone(), other.clone()).map(|(a, b)| a.or(b))
+    }
+
+    /// Combines two optional signals, emitting as soon as either side is available.
+    ///
+    /// Yields `None` while both sides are `None`, and `Some((a, b))` otherwise,
+    /// where the side that has not arrived yet is reported as `None`. Updates on
+    /// every change to either signal.
+    #[allow(clippy::type_complexity)]
+    fn zip_options<T, U, B>(
+        &self,
+        other: &B,
+    ) -> Map<
+        Zip<Self, B>,
+        fn((Option<T>, Option<U>)) -> Option<(Option<T>, Option<U>)>,
+        Option<(Option<T>, Option<U>)>,
+    >
+    where
+        Self: Signal<Output = Option<T>> + 'static,
+        B: Signal<Output = Option<U>> + 'static,
+        T: Clone + 'static,
+        U: Clone + 'static,
+    {
+        self.zip(other).map(|(a, b)| match (a, b) {
+            (None, None) => None,
+            pair => Some(pair),
+        })
+    }
+
     // ==================== Bool Methods ====================
 
     /// Returns the logical negation of the boolean value.
@@ -381,6 +836,130 @@ pub trait SignalExt: Signal {
         self.map(|v| v.is_negative())
     }
 
+    /// Adds `other` to this value, saturating at the numeric bounds instead of overflowing.
+    fn saturating_add<T, B>(&self, other: &B) -> Map<Zip<Self, B>, fn((T, T)) -> T, T>
+    where
+        Self: Signal<Output = T> + 'static,
+        B: Signal<Output = T> + 'static,
+        T: SaturatingAdd + Clone + 'static,
+    {
+        self.zip(other).map(|(a, b)| a.saturating_add(&b))
+    }
+
+    /// Subtracts `other` from this value, saturating at the numeric bounds instead of overflowing.
+    fn saturating_sub<T, B>(&self, other: &B) -> Map<Zip<Self, B>, fn((T, T)) -> T, T>
+    where
+        Self: Signal<Output = T> + 'static,
+        B: Signal<Output = T> + 'static,
+        T: SaturatingSub + Clone + 'static,
+    {
+        self.zip(other).map(|(a, b)| a.saturating_sub(&b))
+    }
+
+    /// Multiplies this value by `other`, saturating at the numeric bounds instead of overflowing.
+    fn saturating_mul<T, B>(&self, other: &B) -> Map<Zip<Self, B>, fn((T, T)) -> T, T>
+    where
+        Self: Signal<Output = T> + 'static,
+        B: Signal<Output = T> + 'static,
+        T: SaturatingMul + Clone + 'static,
+    {
+        self.zip(other).map(|(a, b)| a.saturating_mul(&b))
+    }
+
+    /// Returns the sum with `other`, or `None` on overflow.
+    fn checked_add<T, B>(&self, other: &B) -> Map<Zip<Self, B>, fn((T, T)) -> Option<T>, Option<T>>
+    where
+        Self: Signal<Output = T> + 'static,
+        B: Signal<Output = T> + 'static,
+        T: CheckedAdd + Clone + 'static,
+    {
+        self.zip(other).map(|(a, b)| a.checked_add(&b))
+    }
+
+    /// Returns the difference with `other`, or `None` on overflow.
+    fn checked_sub<T, B>(&self, other: &B) -> Map<Zip<Self, B>, fn((T, T)) -> Option<T>, Option<T>>
+    where
+        Self: Signal<Output = T> + 'static,
+        B: Signal<Output = T> + 'static,
+        T: CheckedSub + Clone + 'static,
+    {
+        self.zip(other).map(|(a, b)| a.checked_sub(&b))
+    }
+
+    /// Returns the product with `other`, or `None` on overflow.
+    fn checked_mul<T, B>(&self, other: &B) -> Map<Zip<Self, B>, fn((T, T)) -> Option<T>, Option<T>>
+    where
+        Self: Signal<Output = T> + 'static,
+        B: Signal<Output = T> + 'static,
+        T: CheckedMul + Clone + 'static,
+    {
+        self.zip(other).map(|(a, b)| a.checked_mul(&b))
+    }
+
+    /// Returns the quotient with `other`, or `None` on overflow or division by zero.
+    fn checked_div<T, B>(&self, other: &B) -> Map<Zip<Self, B>, fn((T, T)) -> Option<T>, Option<T>>
+    where
+        Self: Signal<Output = T> + 'static,
+        B: Signal<Output = T> + 'static,
+        T: CheckedDiv + Clone + 'static,
+    {
+        self.zip(other).map(|(a, b)| a.checked_div(&b))
+    }
+
+    /// Scales a `Duration` signal by a floating-point `factor` signal.
+    ///
+    /// Negative or NaN products saturate to [`Duration::ZERO`] and overflowing
+    /// products to [`Duration::MAX`], so the result never panics.
+    fn scale_duration<B>(
+        &self,
+        factor: &B,
+    ) -> Map<Zip<Self, B>, fn((Duration, f64)) -> Duration, Duration>
+    where
+        Self: Signal<Output = Duration> + 'static,
+        B: Signal<Output = f64> + 'static,
+    {
+        self.zip(factor).map(|(duration, factor)| {
+            let secs = duration.as_secs_f64() * factor;
+            if secs.is_nan() || secs <= 0.0 {
+                Duration::ZERO
+            } else {
+                Duration::try_from_secs_f64(secs).unwrap_or(Duration::MAX)
+            }
+        })
+    }
+
+    /// Rounds a float to the nearest integer (half away from zero) and casts it to `U`.
+    ///
+    /// The cast saturates at `U`'s bounds and maps NaN to zero, as with `as`.
+    fn cast_round<T, U>(&self) -> Map<Self, fn(T) -> U, U>
+    where
+        Self: Signal<Output = T> + 'static,
+        T: FloatCore + AsPrimitive<U>,
+        U: Copy + 'static,
+    {
+        self.map(|v| v.round().as_())
+    }
+
+    /// Rounds a float down and casts it to `U`, saturating like `as`.
+    fn cast_floor<T, U>(&self) -> Map<Self, fn(T) -> U, U>
+    where
+        Self: Signal<Output = T> + 'static,
+        T: FloatCore + AsPrimitive<U>,
+        U: Copy + 'static,
+    {
+        self.map(|v| v.floor().as_())
+    }
+
+    /// Rounds a float up and casts it to `U`, saturating like `as`.
+    fn cast_ceil<T, U>(&self) -> Map<Self, fn(T) -> U, U>
+    where
+        Self: Signal<Output = T> + 'static,
+        T: FloatCore + AsPrimitive<U>,
+        U: Copy + 'static,
+    {
+        self.map(|v| v.ceil().as_())
+    }
+
     /// Returns `true` if the value is zero.
     #[allow(clippy::wrong_self_convention)]
     fn is_zero<T>(&self) -> Map<Self, fn(T) -> bool, bool>
@@ -437,6 +1016,20 @@ pub trait SignalExt: Signal {
         self.map(Result::err)
     }
 
+    /// Splits a `Result` signal into separate ok and error signals.
+    ///
+    /// This is [`ok`](Self::ok) and [`err`](Self::err) as type-erased
+    /// [`Computed`] handles; both follow this signal, so exactly one of them
+    /// holds `Some` at any time.
+    fn split_result<T, E>(&self) -> (Computed<Option<T>>, Computed<Option<E>>)
+    where
+        Self: Signal<Output = Result<T, E>> + 'static,
+        T: Clone + 'static,
+        E: Clone + 'static,
+    {
+        (self.ok().computed(), self.err().computed())
+    }
+
     /// Returns the contained `Ok` value or a default.
     fn unwrap_or_result<T, E>(
         &self,
@@ -496,6 +1089,24 @@ pub trait SignalExt: Signal {
         Map::new(self.clone(), move |r| r.map_err(&f))
     }
 
+    /// Combines two `Result` signals, yielding `Ok((a, b))` only when both are `Ok`.
+    ///
+    /// Otherwise the first error is returned, checking this signal before `other`.
+    #[allow(clippy::type_complexity)]
+    fn zip_results<T, U, E, B>(
+        &self,
+        other: &B,
+    ) -> Map<Zip<Self, B>, fn((Result<T, E>, Result<U, E>)) -> Result<(T, U), E>, Result<(T, U), E>>
+    where
+        Self: Signal<Output = Result<T, E>> + 'static,
+        B: Signal<Output = Result<U, E>> + 'static,
+        T: Clone + 'static,
+        U: Clone + 'static,
+        E: Clone + 'static,
+    {
+        self.zip(other).map(|(a, b)| Ok((a?, b?)))
+    }
+
     // ==================== Timer Methods ====================
 
     #[cfg(feature = "timer")]
@@ -510,6 +1121,29 @@ pub trait SignalExt: Signal {
         Debounce::new(self.clone(), duration)
     }
     #[cfg(feature = "timer")]
+    /// Creates a debounced version of this signal that fires on the leading edge
+    /// and skips repeated values.
+    ///
+    /// The first new value of a burst is delivered immediately and the last one
+    /// after the quiet period, as with [`DebounceBuilder::leading`](crate::debounce::DebounceBuilder::leading).
+    /// Values equal to the previous one are dropped before they reach the
+    /// timer, so they never start a burst or restart the quiet period.
+    /// Returning to the last delivered value within the window cancels the
+    /// pending delivery.
+    fn debounce_distinct_leading(
+        &self,
+        duration: Duration,
+    ) -> Debounce<Self, executor_core::DefaultExecutor>
+    where
+        Self::Output: Clone + PartialEq,
+    {
+        Debounce::builder(self.clone())
+            .duration(duration)
+            .leading(true)
+            .distinct()
+            .build()
+    }
+    #[cfg(feature = "timer")]
     /// Creates a throttled version of this signal.
     ///
     /// The throttled signal will emit values at most once every specified duration,
@@ -557,6 +1191,31 @@ pub trait SignalExt: Signal {
         let pattern = pattern.into();
         Map::new(self.clone(), move |s| s.as_ref().contains(&pattern))
     }
+
+    /// Parses the string into another type using [`FromStr`].
+    ///
+    /// Parse failures are emitted as `Err` values, so invalid input
+    /// (e.g. in a numeric text field) can be observed reactively.
+    ///
+    /// ```
+    /// use nami::{Binding, Signal, SignalExt, binding};
+    ///
+    /// let text: Binding<String> = binding("42".to_string());
+    /// let number = text.parse::<i32>();
+    /// assert_eq!(number.get(), Ok(42));
+    ///
+    /// text.set("forty-two".to_string());
+    /// assert!(number.get().is_err());
+    /// ```
+    fn parse<F>(&self) -> Map<Self, fn(Self::Output) -> Result<F, F::Err>, Result<F, F::Err>>
+    where
+        Self: 'static,
+        Self::Output: AsRef<str>,
+        F: FromStr + 'static,
+        F::Err: 'static,
+    {
+        self.map(|s| s.as_ref().parse())
+    }
 }
 
 impl<C: Signal> SignalExt for C {}
@@ -565,7 +1224,7 @@ impl<C: Signal> SignalExt for C {}
 mod tests {
     use super::*;
     use crate::{Binding, binding};
-    use alloc::string::ToString;
+    use alloc::{string::ToString, vec, vec::Vec};
 
     // ==================== Map Variants ====================
 
@@ -576,6 +1235,174 @@ mod tests {
         assert_eq!(mapped.get(), 42i64);
     }
 
+    #[test]
+    fn test_mirror_cell_tracks_changes() {
+        let source: Binding<i32> = binding(1);
+        let (cell, guard) = source.map(|n| n * 2).mirror_cell();
+        assert_eq!(cell.get(), 2);
+
+        source.set(5);
+        assert_eq!(cell.get(), 10);
+        assert_eq!((0..1000).map(|_| cell.get()).sum::<i32>(), 10_000);
+
+        drop(guard);
+        source.set(7);
+        assert_eq!(
+            cell.get(),
+            10,
+            "the cell stops updating once the guard is dropped"
+        );
+    }
+
+    #[test]
+    fn test_map_stateful_running_difference() {
+        let reading: Binding<i32> = binding(0);
+        let delta = reading.map_stateful(0, |last, value| value - last.replace(value));
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = delta.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        reading.set(10);
+        reading.set(15);
+        reading.set(12);
+        assert_eq!(*seen.borrow(), vec![10, 5, -3]);
+    }
+
+    #[test]
+    fn test_memoize_by_computes_once_per_key() {
+        let input: Binding<i32> = binding(1);
+        let calls = Rc::new(core::cell::Cell::new(0));
+        let calls_clone = calls.clone();
+        let squared = input.memoize_by(
+            |n| *n,
+            move |n| {
+                calls_clone.set(calls_clone.get() + 1);
+                n * n
+            },
+        );
+
+        for value in [1, 2, 1, 2, 1] {
+            input.set(value);
+            assert_eq!(squared.get(), value * value);
+        }
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_memoize_by_evicts_least_recently_used() {
+        let input: Binding<usize> = binding(0usize);
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let calls_clone = calls.clone();
+        let memo = input.memoize_by(
+            |n| *n,
+            move |n| {
+                calls_clone.borrow_mut().push(n);
+                n
+            },
+        );
+
+        for value in 0..MEMOIZE_CAPACITY {
+            input.set(value);
+            let _ = memo.get();
+        }
+        input.set(0);
+        let _ = memo.get();
+        input.set(MEMOIZE_CAPACITY);
+        let _ = memo.get();
+        calls.borrow_mut().clear();
+
+        input.set(0);
+        let _ = memo.get();
+        input.set(1);
+        let _ = memo.get();
+        assert_eq!(
+            *calls.borrow(),
+            vec![1],
+            "key 1 was the least recently used"
+        );
+    }
+
+    #[test]
+    fn test_diff_with_tracks_both_sides() {
+        let before: Binding<Vec<&str>> = binding(vec!["a", "b"]);
+        let after: Binding<Vec<&str>> = binding(vec!["a", "b", "c"]);
+        let added = before.diff_with(&after, |old, new| {
+            new.into_iter()
+                .filter(|item| !old.iter().any(|seen| seen == item))
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(added.get(), vec!["c"]);
+
+        before.set(vec![]);
+        assert_eq!(added.get(), vec!["a", "b", "c"]);
+
+        after.set(vec!["b"]);
+        assert_eq!(added.get(), vec!["b"]);
+    }
+
+    #[test]
+    fn test_diff_with_numeric_difference() {
+        let old: Binding<i64> = binding(10);
+        let new: Binding<i64> = binding(25);
+        let delta = old.diff_with(&new, |old, new| new - old);
+        assert_eq!(delta.get(), 15);
+
+        old.set(40);
+        assert_eq!(delta.get(), -15);
+    }
+
+    #[test]
+    fn test_switch_follows_held_signal() {
+        let a: Binding<i32> = binding(1);
+        let b: Binding<i32> = binding(2);
+        let holder: Binding<Binding<i32>> = binding(a.clone());
+        let current = holder.switch();
+        assert_eq!(current.get(), 1);
+
+        holder.set(b.clone());
+        b.set(5);
+        a.set(7);
+        assert_eq!(current.get(), 5);
+    }
+
+    #[test]
+    fn test_flat_expand_collects_each_expansion() {
+        let keystroke: Binding<char> = binding('a');
+        let events = keystroke.flat_expand(|key: char| [key, key.to_ascii_uppercase()]);
+        assert_eq!(events.get(), vec!['a', 'A']);
+
+        let seen: Rc<RefCell<Vec<Vec<char>>>> = Rc::default();
+        let seen_clone = seen.clone();
+        let _guard = events.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+        keystroke.set('q');
+
+        assert_eq!(*seen.borrow(), vec![vec!['q', 'Q']]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_catch_map() {
+        let signal: Binding<i32> = binding(4);
+        let halved = signal.catch_map(
+            |value| {
+                assert!(value % 2 == 0, "odd input");
+                value / 2
+            },
+            -1,
+        );
+        assert_eq!(halved.get(), 2);
+
+        let seen = alloc::rc::Rc::new(core::cell::RefCell::new(alloc::vec::Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = halved.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        signal.set(3);
+        signal.set(10);
+        assert_eq!(*seen.borrow(), [-1, 5]);
+        assert_eq!(halved.get(), 5);
+    }
+
     #[test]
     fn test_distinct() {
         let signal: Binding<i32> = binding(42);
@@ -583,8 +1410,68 @@ mod tests {
         assert_eq!(distinct.get(), 42);
     }
 
+    #[test]
+    fn test_watch_immediate_calls_with_current_value_first() {
+        let signal: Binding<i32> = binding(3);
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let guard = signal.watch_immediate(move |ctx| {
+            assert!(ctx.metadata().is_empty());
+            seen_clone.borrow_mut().push(ctx.into_value());
+        });
+        assert_eq!(*seen.borrow(), vec![3]);
+
+        signal.set(4);
+        drop(guard);
+        signal.set(5);
+        assert_eq!(*seen.borrow(), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_distinct_fires_once_for_repeated_value() {
+        let signal: Binding<i32> = binding(0);
+        let parity = signal.map(|n| n % 2).distinct();
+
+        let fired = Rc::new(core::cell::Cell::new(0));
+        let fired_clone = fired.clone();
+        let _guard = parity.watch(move |_| fired_clone.set(fired_clone.get() + 1));
+
+        signal.set(1);
+        signal.set(1);
+        signal.set(3);
+        assert_eq!(fired.get(), 1);
+    }
+
     // ==================== Comparison Methods ====================
 
+    #[test]
+    fn test_once_runs_on_first_change_only() {
+        let signal: Binding<i32> = binding(0);
+        let calls: Rc<RefCell<Vec<i32>>> = Rc::default();
+        let calls_clone = calls.clone();
+        let _guard = signal.once(move |value| calls_clone.borrow_mut().push(value));
+
+        signal.set(1);
+        signal.set(2);
+
+        assert_eq!(*calls.borrow(), [1]);
+        assert_eq!(Rc::strong_count(&calls), 1, "the watcher should be removed");
+    }
+
+    #[test]
+    fn test_once_cancelled_by_dropping_guard() {
+        let signal: Binding<i32> = binding(0);
+        let calls: Rc<RefCell<Vec<i32>>> = Rc::default();
+        let calls_clone = calls.clone();
+        let guard = signal.once(move |value| calls_clone.borrow_mut().push(value));
+
+        drop(guard);
+        signal.set(1);
+
+        assert!(calls.borrow().is_empty());
+        assert_eq!(Rc::strong_count(&calls), 1);
+    }
+
     #[test]
     fn test_equal_to() {
         let signal: Binding<i32> = binding(42);
@@ -741,6 +1628,64 @@ mod tests {
         assert_eq!(signal.flatten().get(), None);
     }
 
+    #[test]
+    fn test_or_signal() {
+        let a: Binding<Option<i32>> = binding(Some(1));
+        let b: Binding<Option<i32>> = binding(Some(2));
+        let either = a.or_signal(&b);
+        assert_eq!(either.get(), Some(1));
+
+        let seen = alloc::rc::Rc::new(core::cell::RefCell::new(alloc::vec::Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = either.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        a.set(None);
+        b.set(Some(3));
+        a.set(Some(4));
+        b.set(None);
+        a.set(None);
+
+        assert_eq!(*seen.borrow(), [Some(2), Some(3), Some(4), Some(4), None]);
+    }
+
+    #[test]
+    fn test_zip_options_left_arrives_first() {
+        let a: Binding<Option<i32>> = binding(None);
+        let b: Binding<Option<char>> = binding(None);
+        let either = a.zip_options(&b);
+        assert_eq!(either.get(), None);
+
+        let seen = alloc::rc::Rc::new(core::cell::RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = either.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        a.set(Some(1));
+        b.set(Some('x'));
+        assert_eq!(
+            *seen.borrow(),
+            [Some((Some(1), None)), Some((Some(1), Some('x')))]
+        );
+    }
+
+    #[test]
+    fn test_zip_options_right_arrives_first() {
+        let a: Binding<Option<i32>> = binding(None);
+        let b: Binding<Option<char>> = binding(None);
+        let either = a.zip_options(&b);
+
+        b.set(Some('y'));
+        assert_eq!(either.get(), Some((None, Some('y'))));
+
+        a.set(Some(2));
+        assert_eq!(either.get(), Some((Some(2), Some('y'))));
+
+        b.set(None);
+        assert_eq!(either.get(), Some((Some(2), None)));
+
+        a.set(None);
+        assert_eq!(either.get(), None);
+    }
+
     // ==================== Bool Methods ====================
 
     #[test]
@@ -864,6 +1809,87 @@ mod tests {
         assert!(!signal.is_negative().get());
     }
 
+    #[test]
+    fn test_scale_duration_follows_both_inputs() {
+        let duration = binding(Duration::from_millis(200));
+        let factor = binding(1.5_f64);
+        let scaled = duration.scale_duration(&factor);
+        assert_eq!(scaled.get(), Duration::from_millis(300));
+
+        factor.set(0.5);
+        assert_eq!(scaled.get(), Duration::from_millis(100));
+
+        duration.set(Duration::from_secs(2));
+        assert_eq!(scaled.get(), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_scale_duration_saturates() {
+        let duration = binding(Duration::from_secs(1));
+        let factor = binding(-2.0_f64);
+        let scaled = duration.scale_duration(&factor);
+        assert_eq!(scaled.get(), Duration::ZERO);
+
+        factor.set(f64::NAN);
+        assert_eq!(scaled.get(), Duration::ZERO);
+
+        factor.set(f64::INFINITY);
+        assert_eq!(scaled.get(), Duration::MAX);
+    }
+
+    #[test]
+    fn test_saturating_ops_clamp_at_bounds() {
+        let a: Binding<i8> = binding(120);
+        let b: Binding<i8> = binding(10);
+        let sum = a.saturating_add(&b);
+        let difference = a.saturating_sub(&b);
+        let product = a.saturating_mul(&b);
+        assert_eq!(sum.get(), i8::MAX);
+        assert_eq!(difference.get(), 110);
+        assert_eq!(product.get(), i8::MAX);
+
+        a.set(-120);
+        assert_eq!(sum.get(), -110);
+        assert_eq!(difference.get(), i8::MIN);
+        assert_eq!(product.get(), i8::MIN);
+    }
+
+    #[test]
+    fn test_checked_ops_return_none_on_overflow() {
+        let a: Binding<u8> = binding(250);
+        let b: Binding<u8> = binding(5);
+        let sum = a.checked_add(&b);
+        let difference = b.checked_sub(&a);
+        let product = a.checked_mul(&b);
+        let quotient = a.checked_div(&b);
+        assert_eq!(sum.get(), Some(255));
+        assert_eq!(difference.get(), None);
+        assert_eq!(product.get(), None);
+        assert_eq!(quotient.get(), Some(50));
+
+        b.set(6);
+        assert_eq!(sum.get(), None);
+
+        b.set(0);
+        assert_eq!(quotient.get(), None);
+        assert_eq!(product.get(), Some(0));
+    }
+
+    #[test]
+    fn test_cast_rounding_modes() {
+        let slider: Binding<f64> = binding(2.5);
+        let rounded = slider.cast_round::<f64, i32>();
+        let floored = slider.cast_floor::<f64, i32>();
+        let ceiled = slider.cast_ceil::<f64, u8>();
+        assert_eq!((rounded.get(), floored.get(), ceiled.get()), (3, 2, 3));
+
+        slider.set(-1.2);
+        assert_eq!((rounded.get(), floored.get(), ceiled.get()), (-1, -2, 0));
+
+        slider.set(300.1);
+        assert_eq!(ceiled.get(), u8::MAX, "casts saturate at the target bounds");
+    }
+
     #[test]
     fn test_is_zero() {
         let signal: Binding<i32> = binding(0);
@@ -911,6 +1937,44 @@ mod tests {
         assert_eq!(signal.err().get(), None);
     }
 
+    #[test]
+    fn test_split_result_follows_source() {
+        let source: Binding<Result<i32, &str>> = binding(Ok(1));
+        let (ok, err) = source.split_result();
+        assert_eq!((ok.get(), err.get()), (Some(1), None));
+
+        let errors = Rc::new(RefCell::new(Vec::new()));
+        let errors_clone = errors.clone();
+        let _guard = err.watch(move |ctx| errors_clone.borrow_mut().push(ctx.into_value()));
+
+        source.set(Err("offline"));
+        assert_eq!((ok.get(), err.get()), (None, Some("offline")));
+
+        source.set(Ok(2));
+        assert_eq!((ok.get(), err.get()), (Some(2), None));
+        assert_eq!(*errors.borrow(), vec![Some("offline"), None]);
+    }
+
+    #[test]
+    fn test_zip_results_short_circuits_on_first_error() {
+        let a: Binding<Result<i32, &str>> = binding(Ok(1));
+        let b: Binding<Result<char, &str>> = binding(Ok('x'));
+        let both = a.zip_results(&b);
+        assert_eq!(both.get(), Ok((1, 'x')));
+
+        b.set(Err("b failed"));
+        assert_eq!(both.get(), Err("b failed"));
+
+        a.set(Err("a failed"));
+        assert_eq!(both.get(), Err("a failed"));
+
+        b.set(Ok('y'));
+        assert_eq!(both.get(), Err("a failed"));
+
+        a.set(Ok(2));
+        assert_eq!(both.get(), Ok((2, 'y')));
+    }
+
     // ==================== String Methods ====================
 
     #[test]
@@ -959,4 +2023,33 @@ mod tests {
         signal.set("hello");
         assert!(!has_world.get());
     }
+
+    #[test]
+    fn test_parse() {
+        let signal: Binding<String> = binding("42".to_string());
+        let parsed = signal.parse::<i32>();
+        assert_eq!(parsed.get(), Ok(42));
+
+        signal.set("-7".to_string());
+        assert_eq!(parsed.get(), Ok(-7));
+
+        signal.set("abc".to_string());
+        assert!(parsed.get().is_err());
+    }
+
+    #[test]
+    fn test_parse_notifies_errors() {
+        let signal: Binding<String> = binding("1".to_string());
+        let parsed = signal.parse::<u8>();
+
+        let results = alloc::rc::Rc::new(core::cell::RefCell::new(alloc::vec::Vec::new()));
+        let results_clone = results.clone();
+        let _guard = parsed.watch(move |ctx| {
+            results_clone.borrow_mut().push(ctx.into_value().is_ok());
+        });
+
+        signal.set("300".to_string());
+        signal.set("255".to_string());
+        assert_eq!(*results.borrow(), [false, true]);
+    }
 }
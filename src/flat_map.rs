@@ -0,0 +1,160 @@
+//! # Flat-Map Signal Implementation
+//!
+//! This module provides [`FlatMap`], which flattens a signal of signals so
+//! that watchers always track the inner signal currently selected by the
+//! outer one (often called `switch`).
+
+use core::cell::{Cell, RefCell};
+
+use alloc::{boxed::Box, rc::Rc};
+
+use crate::{
+    Signal,
+    watcher::{BoxWatcherGuard, Context, OnDrop},
+};
+
+/// A signal that follows the inner signal produced from the outer signal's value.
+///
+/// Whenever the outer signal changes, the previous inner subscription is
+/// dropped, the new inner signal is subscribed, and watchers immediately
+/// receive its current value. Inner notifications arriving from a signal that
+/// has since been switched away from are ignored.
+///
+/// Created by [`SignalExt::flat_map`](crate::SignalExt::flat_map).
+#[derive(Debug, Clone)]
+pub struct FlatMap<S, F> {
+    source: S,
+    f: F,
+}
+
+impl<S, F> FlatMap<S, F> {
+    /// Creates a signal following the inner signal `f` selects from `source`.
+    pub const fn new(source: S, f: F) -> Self {
+        Self { source, f }
+    }
+}
+
+impl<S, F, S2> Signal for FlatMap<S, F>
+where
+    S: Signal,
+    F: Fn(S::Output) -> S2 + Clone + 'static,
+    S2: Signal,
+{
+    type Output = S2::Output;
+    type Guard = BoxWatcherGuard;
+
+    fn get(&self) -> Self::Output {
+        (self.f)(self.source.get()).get()
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        let watcher = Rc::new(watcher);
+        let generation = Rc::new(Cell::new(0_u64));
+        let inner: Rc<RefCell<Option<S2::Guard>>> = Rc::default();
+
+        let subscribe = {
+            let watcher = watcher.clone();
+            let generation = generation.clone();
+            move |signal: &S2| {
+                let current = generation.get();
+                let watcher = watcher.clone();
+                let generation = generation.clone();
+                signal.watch(move |ctx| {
+                    if generation.get() == current {
+                        watcher(ctx);
+                    }
+                })
+            }
+        };
+
+        *inner.borrow_mut() = Some(subscribe(&(self.f)(self.source.get())));
+
+        let outer = {
+            let f = self.f.clone();
+            let inner = inner.clone();
+            self.source.watch(move |ctx: Context<S::Output>| {
+                let metadata = ctx.metadata().clone();
+                let signal = f(ctx.into_value());
+
+                // Retire the previous inner signal before switching
+                generation.set(generation.get() + 1);
+                let previous = inner.borrow_mut().take();
+                drop(previous);
+
+                let value = signal.get();
+                *inner.borrow_mut() = Some(subscribe(&signal));
+                watcher(Context::new(value, metadata));
+            })
+        };
+
+        Box::new((outer, OnDrop::new(move || drop(inner.borrow_mut().take()))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Binding, SignalExt, binding};
+    use alloc::{vec, vec::Vec};
+
+    #[test]
+    fn follows_currently_selected_inner_binding() {
+        let first: Binding<i32> = binding(1);
+        let second: Binding<i32> = binding(20);
+        let selected: Binding<bool> = binding(false);
+        let detail = {
+            let (first, second) = (first.clone(), second.clone());
+            selected.flat_map(move |second_selected| {
+                if second_selected {
+                    second.clone()
+                } else {
+                    first.clone()
+                }
+            })
+        };
+        assert_eq!(detail.get(), 1);
+
+        let seen: Rc<RefCell<Vec<i32>>> = Rc::default();
+        let seen_clone = seen.clone();
+        let _guard = detail.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        first.set(2);
+        selected.set(true);
+        first.set(3);
+        second.set(30);
+
+        assert_eq!(*seen.borrow(), vec![2, 20, 30]);
+        assert_eq!(detail.get(), 30);
+    }
+
+    #[test]
+    fn rapid_switching_keeps_one_inner_subscription() {
+        let items: Vec<Binding<i32>> = (0..4).map(binding).collect();
+        let index: Binding<usize> = binding(0_usize);
+        let current = {
+            let items = items.clone();
+            index.flat_map(move |index| items[index].clone())
+        };
+        let seen: Rc<RefCell<Vec<i32>>> = Rc::default();
+        let seen_clone = seen.clone();
+        let guard = current.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        for next in [1, 2, 3, 0, 3] {
+            index.set(next);
+        }
+        seen.borrow_mut().clear();
+        for (offset, item) in items.iter().enumerate() {
+            item.set(10 + i32::try_from(offset).unwrap());
+        }
+        assert_eq!(
+            *seen.borrow(),
+            vec![13],
+            "only the selected inner is watched"
+        );
+
+        drop(guard);
+        items[3].set(99);
+        index.set(1);
+        assert_eq!(*seen.borrow(), vec![13]);
+    }
+}
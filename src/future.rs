@@ -5,9 +5,15 @@
 //! becomes `Some(value)` and notifies watchers.
 //!
 //! This is handy for wiring async computations into a reactive graph.
+//!
+//! The spawned task can be aborted with [`FutureSignal::cancel`], or tied to
+//! the lifetime of a [`FutureSignalHandle`] so that tearing down the code that
+//! started a request also stops it from writing a stale result.
 
+use alloc::{boxed::Box, rc::Rc};
+use core::{cell::RefCell, fmt::Debug};
 use executor_core::{LocalExecutor, Task};
-use nami_core::watcher::Context;
+use nami_core::watcher::{Context, WatcherGuard};
 
 use crate::{Container, CustomBinding, Signal};
 
@@ -15,9 +21,43 @@ use crate::{Container, CustomBinding, Signal};
 ///
 /// The signal yields `None` until the future resolves, and `Some(value)`
 /// afterwards. Watchers are notified when the value becomes available.
-#[derive(Debug, Clone)]
+///
+/// Clones share the same container and the same spawned task. Dropping every
+/// clone does not cancel the task; use [`cancel`](Self::cancel) or a
+/// [`FutureSignalHandle`] for that.
 pub struct FutureSignal<T: 'static + Clone> {
     container: Container<Option<T>>,
+    task: Rc<RefCell<Option<Box<dyn Task<()>>>>>,
+}
+
+impl<T: Clone + Debug> Debug for FutureSignal<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FutureSignal")
+            .field("container", &self.container)
+            .field("pending", &self.task.borrow().is_some())
+            .finish()
+    }
+}
+
+impl<T: Clone> Clone for FutureSignal<T> {
+    fn clone(&self) -> Self {
+        Self {
+            container: self.container.clone(),
+            task: self.task.clone(),
+        }
+    }
+}
+
+impl<T: Clone> Drop for FutureSignal<T> {
+    fn drop(&mut self) {
+        // Keep the historical fire-and-forget behaviour: the last owner
+        // detaches the task instead of letting the drop cancel it.
+        if Rc::strong_count(&self.task) == 1
+            && let Some(task) = self.task.borrow_mut().take()
+        {
+            core::mem::forget(task);
+        }
+    }
 }
 
 impl<T> FutureSignal<T>
@@ -38,23 +78,68 @@ where
     /// Spawn the future on the given executor and create a `FutureSignal`.
     pub fn with_executor<E, Fut>(executor: E, fut: Fut) -> Self
     where
-        E: LocalExecutor,
+        E: LocalExecutor + 'static,
         Fut: Future<Output = T> + 'static,
     {
         let container = Container::default();
-        {
+        let task = {
             let container = container.clone();
-            executor
-                .spawn_local(async move {
-                    let value = fut.await;
-                    container.set(Some(value));
-                })
-                .detach();
+            executor.spawn_local(async move {
+                let value = fut.await;
+                container.set(Some(value));
+            })
+        };
+        Self {
+            container,
+            task: Rc::new(RefCell::new(Some(Box::new(task)))),
         }
-        Self { container }
+    }
+
+    /// Aborts the spawned task so it can no longer set the value.
+    ///
+    /// Cancellation is best-effort: it drops the executor's task handle, and
+    /// whether that stops the future depends on the executor. Calling this
+    /// after the future resolved, or more than once, has no effect.
+    pub fn cancel(&self) {
+        // Drop the task outside the borrow in case the executor polls on drop.
+        let task = self.task.borrow_mut().take();
+        drop(task);
+    }
+
+    /// Returns a guard that cancels the spawned task when dropped.
+    ///
+    /// Keep the handle alongside whatever started the request; dropping it
+    /// has the same best-effort semantics as [`cancel`](Self::cancel).
+    pub fn handle(&self) -> FutureSignalHandle {
+        FutureSignalHandle {
+            task: self.task.clone(),
+        }
+    }
+}
+
+/// A guard that cancels a [`FutureSignal`]'s task when dropped.
+#[must_use = "dropping the handle cancels the future"]
+pub struct FutureSignalHandle {
+    task: Rc<RefCell<Option<Box<dyn Task<()>>>>>,
+}
+
+impl Debug for FutureSignalHandle {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FutureSignalHandle")
+            .field("pending", &self.task.borrow().is_some())
+            .finish()
+    }
+}
+
+impl Drop for FutureSignalHandle {
+    fn drop(&mut self) {
+        let task = self.task.borrow_mut().take();
+        drop(task);
     }
 }
 
+impl WatcherGuard for FutureSignalHandle {}
+
 impl<T> Signal for FutureSignal<T>
 where
     T: Clone + 'static,
@@ -70,3 +155,55 @@ where
         self.container.watch(watcher)
     }
 }
+
+#[cfg(all(test, feature = "timer"))]
+mod tests {
+    use super::*;
+    use crate::testing::ManualTimer;
+    use core::time::Duration;
+
+    const TICK: Duration = Duration::from_millis(5);
+
+    #[test]
+    fn resolves_when_not_cancelled() {
+        let timer = ManualTimer::new();
+        let signal = FutureSignal::with_executor(timer.clone(), async { 7 });
+        let _handle = signal.handle();
+        assert_eq!(signal.get(), None);
+
+        timer.advance(TICK);
+        assert_eq!(signal.get(), Some(7));
+    }
+
+    #[test]
+    fn dropping_handle_before_resolution_cancels() {
+        let timer = ManualTimer::new();
+        let signal = FutureSignal::with_executor(timer.clone(), async { 7 });
+        drop(signal.handle());
+
+        timer.advance(TICK);
+        assert_eq!(signal.get(), None);
+    }
+
+    #[test]
+    fn cancel_stops_pending_future() {
+        let timer = ManualTimer::new();
+        let signal = FutureSignal::with_executor(timer.clone(), async { 7 });
+        signal.cancel();
+        signal.cancel();
+
+        timer.advance(TICK);
+        assert_eq!(signal.get(), None);
+    }
+
+    #[test]
+    fn dropping_signal_keeps_task_running() {
+        let timer = ManualTimer::new();
+        let signal = FutureSignal::with_executor(timer.clone(), async { 7 });
+        let container = signal.container.clone();
+        drop(signal);
+
+        timer.advance(TICK);
+        assert_eq!(container.get(), Some(7));
+    }
+}
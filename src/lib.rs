@@ -1,7 +1,7 @@
 #![no_std]
 #![doc = include_str!("../README.md")]
 
-#[cfg(test)]
+#[cfg(any(test, feature = "std"))]
 extern crate std;
 
 extern crate alloc;
@@ -9,6 +9,13 @@ extern crate alloc;
 #[macro_use]
 mod ops;
 
+pub mod active;
+// Mirrors need 64-bit atomics and `Arc`, which some embedded targets lack.
+#[cfg(all(target_has_atomic = "64", target_has_atomic = "ptr"))]
+pub mod atomic;
+pub mod batch;
+#[doc(inline)]
+pub use batch::batch;
 pub mod binding;
 #[doc(inline)]
 pub use binding::{Binding, Container, CustomBinding, binding};
@@ -24,19 +31,32 @@ pub mod collection;
 pub mod debounce;
 pub mod debug;
 pub mod distinct;
+pub mod environment;
+pub mod event_log;
 
 mod ext;
+pub mod flat_map;
 pub mod future;
 pub mod map;
+pub mod map_while;
 /// Projection utilities for decomposing bindings into component parts.
 pub mod project;
+pub mod pull;
+pub mod race;
+pub mod refcell;
+pub mod sample;
+pub mod scan;
+pub mod state_machine;
 pub mod stream;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 #[cfg(feature = "timer")]
 /// Throttling utilities for limiting signal update rates.
 pub mod throttle;
 #[doc(inline)]
 pub use project::Project;
 pub mod utils;
+pub mod validate;
 pub use nami_core::watcher;
 pub mod zip;
 #[doc(inline)]
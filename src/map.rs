@@ -21,9 +21,9 @@
 //! doubled.get(); // Uses cached value, doesn't recompute
 //! ```
 
-use core::marker::PhantomData;
+use core::{any::Any, marker::PhantomData};
 
-use crate::{Signal, watcher::Context};
+use crate::{Binding, Container, Signal, watcher::Context};
 
 /// A reactive computation that transforms values from a source computation.
 ///
@@ -130,3 +130,136 @@ where
 }
 
 impl_signal_ops!(Map<C, F, Output>, [C, F, Output], Output);
+
+/// A transformation that reads its source by reference.
+///
+/// Created by [`SignalExt::map_ref`](crate::SignalExt::map_ref). When the
+/// source is a container-backed [`Binding`] or a [`Container`], `get` borrows
+/// the stored value instead of cloning it, which avoids copying a large value
+/// to read one field. Any other source is still cloned with `get` first.
+/// Notifications already carry an owned value, so watching never clones extra.
+#[derive(Debug)]
+pub struct MapRef<C, F, Output> {
+    source: C,
+    f: F,
+    _marker: PhantomData<Output>,
+}
+
+impl<C, F, Output> MapRef<C, F, Output>
+where
+    C: Signal,
+    F: 'static + Clone + Fn(&C::Output) -> Output,
+    Output: 'static,
+{
+    /// Creates a new `MapRef` that transforms borrows of `source`'s values with `f`.
+    pub const fn new(source: C, f: F) -> Self {
+        Self {
+            source,
+            f,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C: Clone, F: Clone, Output> Clone for MapRef<C, F, Output> {
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source.clone(),
+            f: self.f.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C, F, Output> Signal for MapRef<C, F, Output>
+where
+    C: Signal,
+    F: 'static + Clone + Fn(&C::Output) -> Output,
+    Output: 'static,
+{
+    type Output = Output;
+    type Guard = C::Guard;
+
+    /// Transforms a borrow of the source value, cloning only for non-container sources.
+    fn get(&self) -> Output {
+        let source = &self.source as &dyn Any;
+        if let Some(binding) = source.downcast_ref::<Binding<C::Output>>() {
+            return binding.with_ref(&self.f);
+        }
+        if let Some(container) = source.downcast_ref::<Container<C::Output>>() {
+            return container.with_ref(&self.f);
+        }
+        (self.f)(&self.source.get())
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        let f = self.f.clone();
+        self.source
+            .watch(move |context| watcher(context.map(|value| f(&value))))
+    }
+}
+
+impl_signal_ops!(MapRef<C, F, Output>, [C, F, Output], Output);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Binding, Signal, SignalExt, binding};
+    use alloc::{rc::Rc, string::String, vec, vec::Vec};
+    use core::cell::{Cell, RefCell};
+
+    /// Counts its clones, to observe which reads copy the whole value.
+    struct Tracked {
+        name: String,
+        clones: Rc<Cell<usize>>,
+    }
+
+    impl Clone for Tracked {
+        fn clone(&self) -> Self {
+            self.clones.set(self.clones.get() + 1);
+            Self {
+                name: self.name.clone(),
+                clones: self.clones.clone(),
+            }
+        }
+    }
+
+    #[test]
+    fn map_ref_on_container_does_not_clone() {
+        let clones = Rc::new(Cell::new(0));
+        let source: Binding<Tracked> = binding(Tracked {
+            name: "large".into(),
+            clones: clones.clone(),
+        });
+        let by_ref = source.map_ref(|value| value.name.len());
+        let by_value = source.map(|value| value.name.len());
+
+        for _ in 0..100 {
+            assert_eq!(by_ref.get(), 5);
+        }
+        assert_eq!(clones.get(), 0);
+
+        assert_eq!(by_value.get(), 5);
+        assert_eq!(clones.get(), 1);
+    }
+
+    #[test]
+    fn map_ref_matches_map_for_container_and_mapped_sources() {
+        let source: Binding<Vec<i32>> = binding(vec![1, 2, 3]);
+        let mapped = source.map(|items| items.into_iter().rev().collect::<Vec<_>>());
+        let first_ref = mapped.map_ref(|items| items[0]);
+        let first = mapped.map(|items| items[0]);
+        let len_ref = source.map_ref(Vec::len);
+        let len = source.map(|items| items.len());
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = first_ref.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        for next in [vec![4], vec![5, 6, 7, 8]] {
+            source.set(next);
+            assert_eq!(first_ref.get(), first.get());
+            assert_eq!(len_ref.get(), len.get());
+        }
+        assert_eq!(*seen.borrow(), vec![4, 8]);
+    }
+}
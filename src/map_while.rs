@@ -0,0 +1,144 @@
+//! # Map-While Signal Implementation
+//!
+//! This module provides [`MapWhile`], a signal that maps emissions of a source
+//! and permanently stops forwarding once the mapping first returns `None`.
+
+use core::{any::Any, cell::RefCell};
+
+use alloc::{boxed::Box, rc::Rc};
+
+use crate::{
+    Signal,
+    watcher::{Context, WatcherManager, WatcherManagerGuard},
+};
+
+/// A signal that forwards mapped values until the mapping returns `None`.
+///
+/// The mapping is applied to the source's current value at construction and
+/// to every later emission. On the first `None` the upstream subscription is
+/// dropped and no further values are forwarded. `get()` returns the last
+/// forwarded value, or `None` if the very first mapping already returned `None`.
+#[derive(Debug, Clone)]
+pub struct MapWhile<U> {
+    last: Rc<RefCell<Option<U>>>,
+    watchers: WatcherManager<Option<U>>,
+    upstream: Rc<RefCell<Option<Box<dyn Any>>>>,
+}
+
+impl<U: Clone + 'static> MapWhile<U> {
+    /// Creates a new map-while signal over `source`.
+    pub fn new<S, F>(source: &S, f: F) -> Self
+    where
+        S: Signal,
+        F: Fn(S::Output) -> Option<U> + 'static,
+    {
+        let initial = f(source.get());
+        let active = initial.is_some();
+        let last = Rc::new(RefCell::new(initial));
+        let watchers = WatcherManager::new();
+        let upstream: Rc<RefCell<Option<Box<dyn Any>>>> = Rc::default();
+
+        if active {
+            let guard = {
+                let last = last.clone();
+                let watchers = watchers.clone();
+                // Weak, as `upstream` owns this watcher's guard.
+                let upstream = Rc::downgrade(&upstream);
+                source.watch(move |ctx: Context<S::Output>| {
+                    let Some(upstream) = upstream.upgrade() else {
+                        return;
+                    };
+                    let metadata = ctx.metadata().clone();
+                    let Some(value) = f(ctx.into_value()) else {
+                        let guard = upstream.borrow_mut().take();
+                        drop(guard);
+                        return;
+                    };
+                    *last.borrow_mut() = Some(value.clone());
+                    watchers.notify(&Context::new(Some(value), metadata));
+                })
+            };
+            *upstream.borrow_mut() = Some(Box::new(guard));
+        }
+
+        Self {
+            last,
+            watchers,
+            upstream,
+        }
+    }
+
+    /// Returns `true` while values are still being forwarded.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.upstream.borrow().is_some()
+    }
+}
+
+impl<U: Clone + 'static> Signal for MapWhile<U> {
+    type Output = Option<U>;
+    type Guard = WatcherManagerGuard<Option<U>>;
+
+    fn get(&self) -> Self::Output {
+        self.last.borrow().clone()
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.watchers.register_as_guard(watcher)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Binding, SignalExt, binding};
+    use alloc::{vec, vec::Vec};
+
+    #[test]
+    fn stops_forwarding_after_first_none() {
+        let source: Binding<i32> = binding(1);
+        let positive = source.map_while(|value| (value > 0).then_some(value * 10));
+
+        let seen: Rc<RefCell<Vec<Option<i32>>>> = Rc::default();
+        let seen_clone = seen.clone();
+        let _guard = positive.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        source.set(2);
+        source.set(3);
+        source.set(-1);
+        assert!(!positive.is_active());
+        source.set(4);
+
+        assert_eq!(*seen.borrow(), vec![Some(20), Some(30)]);
+        assert_eq!(positive.get(), Some(30));
+    }
+
+    #[test]
+    fn dropping_every_handle_unsubscribes() {
+        let source: Binding<i32> = binding(1);
+        let calls = Rc::new(core::cell::Cell::new(0));
+        let positive = {
+            let calls = calls.clone();
+            source.map_while(move |value| {
+                calls.set(calls.get() + 1);
+                Some(value)
+            })
+        };
+        assert_eq!(calls.get(), 1);
+
+        drop(positive);
+        source.set(2);
+        source.set(3);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn initial_none_never_subscribes() {
+        let source: Binding<i32> = binding(0);
+        let positive = source.map_while(|value| (value > 0).then_some(value));
+
+        source.set(5);
+        assert!(!positive.is_active());
+        assert_eq!(positive.get(), None);
+    }
+}
@@ -0,0 +1,81 @@
+//! # Pull-based Signal Implementation
+//!
+//! This module provides a signal that is evaluated only on demand.
+//! Unlike [`Lazy`](crate::constant::Lazy), which computes once and caches, or
+//! [`Cached`](crate::cache::Cached), which caches until the source changes,
+//! a [`Pull`] recomputes its source on every `get()` and never notifies watchers.
+
+use crate::{Signal, watcher::Context};
+
+/// A signal that recomputes on every read and never notifies watchers.
+///
+/// `Pull<S>` models "compute on demand only" semantics: upstream changes are
+/// not propagated, but each call to `get()` returns a fresh value from the source.
+#[derive(Debug, Clone)]
+pub struct Pull<S> {
+    source: S,
+}
+
+impl<S: Signal> Pull<S> {
+    /// Creates a new pull-based wrapper around the provided signal.
+    pub const fn new(source: S) -> Self {
+        Self { source }
+    }
+}
+
+impl<S: Signal> Signal for Pull<S> {
+    type Output = S::Output;
+    type Guard = ();
+
+    fn get(&self) -> Self::Output {
+        self.source.get()
+    }
+
+    /// Watchers are never notified, so this does nothing.
+    fn watch(&self, _watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {}
+}
+
+impl_signal_wrapper_ops!(Pull<S>, [S], S);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Binding, SignalExt, binding};
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+
+    #[test]
+    fn pull_never_notifies_watchers() {
+        let source: Binding<i32> = binding(1);
+        let pulled = source.pull();
+
+        let notified = Rc::new(Cell::new(0));
+        let notified_clone = notified.clone();
+        let () = pulled.watch(move |_| notified_clone.set(notified_clone.get() + 1));
+
+        source.set(2);
+        source.set(3);
+        assert_eq!(notified.get(), 0);
+    }
+
+    #[test]
+    fn pull_recomputes_on_every_get() {
+        let source: Binding<i32> = binding(1);
+        let calls = Rc::new(Cell::new(0));
+        let calls_clone = calls.clone();
+        let pulled = source
+            .map(move |value| {
+                calls_clone.set(calls_clone.get() + 1);
+                value * 10
+            })
+            .pull();
+
+        assert_eq!(pulled.get(), 10);
+        assert_eq!(pulled.get(), 10);
+        assert_eq!(calls.get(), 2);
+
+        source.set(4);
+        assert_eq!(pulled.get(), 40);
+        assert_eq!(calls.get(), 3);
+    }
+}
@@ -0,0 +1,168 @@
+//! # Race Signal Implementation
+//!
+//! This module provides [`Race`], a signal that follows whichever of two
+//! sources was updated most recently.
+
+use core::{any::Any, cell::Cell};
+
+use alloc::rc::Rc;
+
+use crate::{
+    Signal,
+    watcher::{Context, WatcherManager, WatcherManagerGuard},
+};
+
+/// Identifies which source of a [`Race`] was updated most recently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// The first source.
+    First,
+    /// The second source.
+    Second,
+}
+
+/// A signal that reflects the most recently updated of two sources.
+///
+/// Before either source changes, the first source is considered the latest.
+/// `get()` returns the current value of the latest-updated source, and
+/// watchers receive every value emitted by either source.
+#[derive(Debug, Clone)]
+pub struct Race<A: Signal, B> {
+    a: A,
+    b: B,
+    latest: Rc<Cell<Side>>,
+    watchers: WatcherManager<A::Output>,
+    _guard: Rc<dyn Any>,
+}
+
+impl<A, B> Race<A, B>
+where
+    A: Signal,
+    B: Signal<Output = A::Output>,
+    A::Output: Clone,
+{
+    /// Creates a new race between two signals.
+    pub fn new(a: A, b: B) -> Self {
+        let latest = Rc::new(Cell::new(Side::First));
+        let watchers = WatcherManager::new();
+
+        let forward = |side: Side| {
+            let latest = latest.clone();
+            let watchers = watchers.clone();
+            move |ctx: Context<A::Output>| {
+                latest.set(side);
+                watchers.notify(&ctx);
+            }
+        };
+        let guard = (
+            a.watch(forward(Side::First)),
+            b.watch(forward(Side::Second)),
+        );
+
+        Self {
+            a,
+            b,
+            latest,
+            watchers,
+            _guard: Rc::new(guard),
+        }
+    }
+
+    /// Returns which source was updated most recently.
+    #[must_use]
+    pub fn latest(&self) -> Side {
+        self.latest.get()
+    }
+}
+
+impl<A, B> Signal for Race<A, B>
+where
+    A: Signal,
+    B: Signal<Output = A::Output>,
+    A::Output: Clone,
+{
+    type Output = A::Output;
+    type Guard = WatcherManagerGuard<A::Output>;
+
+    fn get(&self) -> Self::Output {
+        match self.latest.get() {
+            Side::First => self.a.get(),
+            Side::Second => self.b.get(),
+        }
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.watchers.register_as_guard(watcher)
+    }
+}
+
+/// Creates a signal following whichever of `a` and `b` updated most recently.
+///
+/// This is a convenience function equivalent to `Race::new(a, b)`.
+///
+/// # Example
+///
+/// ```rust
+/// use nami::{Binding, Signal, binding};
+/// use nami::race::race;
+///
+/// let typed: Binding<i32> = binding(1);
+/// let slider: Binding<i32> = binding(50);
+/// let value = race(typed.clone(), slider.clone());
+///
+/// slider.set(60);
+/// assert_eq!(value.get(), 60);
+/// typed.set(2);
+/// assert_eq!(value.get(), 2);
+/// ```
+pub fn race<A, B>(a: A, b: B) -> Race<A, B>
+where
+    A: Signal,
+    B: Signal<Output = A::Output>,
+    A::Output: Clone,
+{
+    Race::new(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Binding, binding};
+    use alloc::{vec, vec::Vec};
+    use core::cell::RefCell;
+
+    #[test]
+    fn get_follows_latest_update() {
+        let a: Binding<i32> = binding(1);
+        let b: Binding<i32> = binding(2);
+        let raced = race(a.clone(), b.clone());
+        assert_eq!(raced.get(), 1);
+        assert_eq!(raced.latest(), Side::First);
+
+        b.set(20);
+        assert_eq!(raced.get(), 20);
+        assert_eq!(raced.latest(), Side::Second);
+
+        a.set(10);
+        assert_eq!(raced.get(), 10);
+
+        b.set(30);
+        assert_eq!(raced.get(), 30);
+    }
+
+    #[test]
+    fn watchers_see_both_sources() {
+        let a: Binding<i32> = binding(0);
+        let b: Binding<i32> = binding(0);
+        let raced = race(a.clone(), b.clone());
+
+        let seen: Rc<RefCell<Vec<i32>>> = Rc::default();
+        let seen_clone = seen.clone();
+        let _guard = raced.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        a.set(1);
+        b.set(2);
+        a.set(3);
+        assert_eq!(*seen.borrow(), vec![1, 2, 3]);
+    }
+}
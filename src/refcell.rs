@@ -0,0 +1,144 @@
+//! # Signals over External `RefCell` State
+//!
+//! This module provides [`RefCellSignal`], a bridge for code that already keeps
+//! its state in an `Rc<RefCell<T>>`. The cell stays owned by the existing code;
+//! the signal reads it on `get()` and notifies watchers when [`RefCellSignal::notify`]
+//! is called after an external mutation.
+//!
+//! ```rust
+//! use core::cell::RefCell;
+//! use std::rc::Rc;
+//! use nami::Signal;
+//! use nami::refcell::RefCellSignal;
+//!
+//! let shared = Rc::new(RefCell::new(1));
+//! let signal = RefCellSignal::new(shared.clone());
+//!
+//! *shared.borrow_mut() = 2;
+//! signal.notify();
+//! assert_eq!(signal.get(), 2);
+//! ```
+
+use core::cell::RefCell;
+
+use alloc::rc::Rc;
+
+use crate::{
+    CustomBinding, Signal,
+    watcher::{Context, WatcherManager, WatcherManagerGuard},
+};
+
+/// A signal backed by an externally owned `Rc<RefCell<T>>`.
+///
+/// Mutations made directly through the cell are not observed automatically;
+/// call [`RefCellSignal::notify`] afterwards to propagate them to watchers.
+/// Writes made through [`CustomBinding::set`] notify watchers on their own.
+#[derive(Debug)]
+pub struct RefCellSignal<T> {
+    cell: Rc<RefCell<T>>,
+    watchers: WatcherManager<T>,
+}
+
+impl<T> Clone for RefCellSignal<T> {
+    fn clone(&self) -> Self {
+        Self {
+            cell: self.cell.clone(),
+            watchers: self.watchers.clone(),
+        }
+    }
+}
+
+impl<T: Clone + 'static> RefCellSignal<T> {
+    /// Wraps an existing shared cell.
+    #[must_use]
+    pub fn new(cell: Rc<RefCell<T>>) -> Self {
+        Self {
+            cell,
+            watchers: WatcherManager::new(),
+        }
+    }
+
+    /// Returns the wrapped cell.
+    #[must_use]
+    pub const fn cell(&self) -> &Rc<RefCell<T>> {
+        &self.cell
+    }
+
+    /// Notifies watchers with the cell's current value.
+    ///
+    /// Call this after mutating the cell externally.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell is currently mutably borrowed.
+    pub fn notify(&self) {
+        if self.watchers.is_empty() {
+            return;
+        }
+        let value = self.cell.borrow().clone();
+        self.watchers.notify(&Context::from(value));
+    }
+}
+
+impl<T: Clone + 'static> Signal for RefCellSignal<T> {
+    type Output = T;
+    type Guard = WatcherManagerGuard<T>;
+
+    fn get(&self) -> Self::Output {
+        self.cell.borrow().clone()
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.watchers.register_as_guard(watcher)
+    }
+}
+
+impl<T: Clone + 'static> CustomBinding for RefCellSignal<T> {
+    fn set(&self, value: T) {
+        *self.cell.borrow_mut() = value;
+        self.notify();
+    }
+}
+
+impl_signal_ops!(RefCellSignal<T>, [T], T);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Binding;
+    use alloc::{vec, vec::Vec};
+
+    #[test]
+    fn external_mutation_is_visible_after_notify() {
+        let shared = Rc::new(RefCell::new(vec![1]));
+        let signal = RefCellSignal::new(shared.clone());
+
+        let seen: Rc<RefCell<Vec<Vec<i32>>>> = Rc::default();
+        let seen_clone = seen.clone();
+        let _guard = signal.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        shared.borrow_mut().push(2);
+        assert_eq!(signal.get(), vec![1, 2]);
+        assert!(
+            seen.borrow().is_empty(),
+            "external mutation alone must not notify"
+        );
+
+        signal.notify();
+        assert_eq!(*seen.borrow(), vec![vec![1, 2]]);
+    }
+
+    #[test]
+    fn set_writes_through_and_notifies() {
+        let shared = Rc::new(RefCell::new(1));
+        let binding = Binding::custom(RefCellSignal::new(shared.clone()));
+
+        let seen: Rc<RefCell<Vec<i32>>> = Rc::default();
+        let seen_clone = seen.clone();
+        let _guard = binding.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        binding.set(5);
+        assert_eq!(*shared.borrow(), 5);
+        assert_eq!(*seen.borrow(), vec![5]);
+    }
+}
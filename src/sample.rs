@@ -0,0 +1,73 @@
+//! # Sampling Signals
+//!
+//! This module provides [`Sample`], which reads a source signal only at the
+//! moments another signal fires, such as a pointer position at each click.
+
+use crate::{Signal, watcher::Context};
+
+/// A signal that notifies with its source's current value whenever a trigger fires.
+///
+/// Changes to the source alone are ignored; only trigger notifications are
+/// forwarded, carrying `source.get()` at that moment. Reading with `get()`
+/// returns the source's current value directly.
+#[derive(Debug, Clone)]
+pub struct Sample<S, T> {
+    source: S,
+    trigger: T,
+}
+
+impl<S: Signal, T: Signal> Sample<S, T> {
+    /// Samples `source` each time `trigger` notifies.
+    pub const fn new(source: S, trigger: T) -> Self {
+        Self { source, trigger }
+    }
+}
+
+impl<S: Signal, T: Signal> Signal for Sample<S, T> {
+    type Output = S::Output;
+    type Guard = T::Guard;
+
+    fn get(&self) -> Self::Output {
+        self.source.get()
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        let source = self.source.clone();
+        self.trigger
+            .watch(move |_| watcher(Context::from(source.get())))
+    }
+}
+
+impl_signal_wrapper_ops!(Sample<S, T>, [S, T], S);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Binding, SignalExt, binding};
+    use alloc::{rc::Rc, vec, vec::Vec};
+    use core::cell::RefCell;
+
+    #[test]
+    fn sample_notifies_only_when_trigger_fires() {
+        let position: Binding<(i32, i32)> = binding((0, 0));
+        let clicks: Binding<u32> = binding(0u32);
+        let clicked_at = position.sample(&clicks);
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = clicked_at.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        for x in 1..=5 {
+            position.set((x, x * 2));
+        }
+        clicks.set(1);
+        for x in 6..=9 {
+            position.set((x, 0));
+        }
+        clicks.set(2);
+        position.set((100, 100));
+
+        assert_eq!(*seen.borrow(), vec![(5, 10), (9, 0)]);
+        assert_eq!(clicked_at.get(), (100, 100));
+    }
+}
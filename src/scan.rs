@@ -0,0 +1,207 @@
+//! # Scan Signal Implementation
+//!
+//! This module provides [`ScanFilter`], a signal that folds every emission of a
+//! source into an accumulator and lets the step function decide whether the
+//! updated accumulator should be emitted to watchers, and [`MapStateful`], a
+//! signal that maps each emission with access to state carried between them.
+
+use core::{any::Any, cell::RefCell};
+
+use alloc::rc::Rc;
+
+use crate::{
+    Signal,
+    watcher::{Context, WatcherManager, WatcherManagerGuard},
+};
+
+/// A signal that accumulates source emissions and selectively notifies watchers.
+///
+/// Each value emitted by the source is passed to the step function together with
+/// a mutable reference to the accumulator. The accumulator is always updated, but
+/// watchers are only notified when the step function returns `true`.
+///
+/// The source's value at construction time is not folded in; only subsequent
+/// emissions are. `get()` returns the current accumulator.
+#[derive(Debug, Clone)]
+pub struct ScanFilter<St> {
+    state: Rc<RefCell<St>>,
+    watchers: WatcherManager<St>,
+    _guard: Rc<dyn Any>,
+}
+
+impl<St: Clone + 'static> ScanFilter<St> {
+    /// Creates a new scanning signal over `source`, starting from `init`.
+    pub fn new<S, F>(source: &S, init: St, step: F) -> Self
+    where
+        S: Signal,
+        F: Fn(&mut St, S::Output) -> bool + 'static,
+    {
+        let state = Rc::new(RefCell::new(init));
+        let watchers = WatcherManager::new();
+        let guard = {
+            let state = state.clone();
+            let watchers = watchers.clone();
+            source.watch(move |ctx: Context<S::Output>| {
+                let metadata = ctx.metadata().clone();
+                let mut current = state.borrow_mut();
+                if !step(&mut current, ctx.into_value()) {
+                    return;
+                }
+                let snapshot = current.clone();
+                drop(current);
+                watchers.notify(&Context::new(snapshot, metadata));
+            })
+        };
+
+        Self {
+            state,
+            watchers,
+            _guard: Rc::new(guard),
+        }
+    }
+}
+
+impl<St: Clone + 'static> Signal for ScanFilter<St> {
+    type Output = St;
+    type Guard = WatcherManagerGuard<St>;
+
+    fn get(&self) -> Self::Output {
+        self.state.borrow().clone()
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.watchers.register_as_guard(watcher)
+    }
+}
+
+impl_signal_ops!(ScanFilter<St>, [St], St);
+
+/// A signal that maps source emissions with access to mutable state.
+///
+/// The mapping runs once for the source's value at construction and once per
+/// later emission, inside a single subscription shared by all clones. Its
+/// result is cached, so `get()` and additional watchers do not advance the
+/// state.
+#[derive(Debug, Clone)]
+pub struct MapStateful<U> {
+    cache: Rc<RefCell<U>>,
+    watchers: WatcherManager<U>,
+    _guard: Rc<dyn Any>,
+}
+
+impl<U: Clone + 'static> MapStateful<U> {
+    /// Creates a new stateful mapping over `source`, with state seeded from `init`.
+    pub fn new<S, St, F>(source: &S, init: St, f: F) -> Self
+    where
+        S: Signal,
+        St: 'static,
+        F: Fn(&RefCell<St>, S::Output) -> U + 'static,
+    {
+        let state = RefCell::new(init);
+        let cache = Rc::new(RefCell::new(f(&state, source.get())));
+        let watchers = WatcherManager::new();
+        let guard = {
+            let cache = cache.clone();
+            let watchers = watchers.clone();
+            source.watch(move |ctx: Context<S::Output>| {
+                let metadata = ctx.metadata().clone();
+                let value = f(&state, ctx.into_value());
+                cache.replace(value.clone());
+                watchers.notify(&Context::new(value, metadata));
+            })
+        };
+
+        Self {
+            cache,
+            watchers,
+            _guard: Rc::new(guard),
+        }
+    }
+}
+
+impl<U: Clone + 'static> Signal for MapStateful<U> {
+    type Output = U;
+    type Guard = WatcherManagerGuard<U>;
+
+    fn get(&self) -> Self::Output {
+        self.cache.borrow().clone()
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.watchers.register_as_guard(watcher)
+    }
+}
+
+impl_signal_ops!(MapStateful<U>, [U], U);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Binding, SignalExt, binding};
+    use alloc::{vec, vec::Vec};
+
+    #[test]
+    fn emits_only_when_crossing_thresholds() {
+        let source: Binding<i32> = binding(0);
+        // Emit whenever the running total crosses into a new block of 10.
+        let total = source.scan_filter(0, |sum: &mut i32, value| {
+            let before = *sum / 10;
+            *sum += value;
+            *sum / 10 != before
+        });
+
+        let seen: Rc<RefCell<Vec<i32>>> = Rc::default();
+        let seen_clone = seen.clone();
+        let _guard = total.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        for value in [3, 4, 5, 1, 8, 2] {
+            source.set(value);
+        }
+
+        assert_eq!(*seen.borrow(), vec![12, 21]);
+        assert_eq!(total.get(), 23);
+    }
+
+    #[test]
+    fn accumulator_updates_without_emission() {
+        let source: Binding<i32> = binding(0);
+        let count = source.scan_filter(0_usize, |count, _| {
+            *count += 1;
+            false
+        });
+
+        let notified = Rc::new(RefCell::new(false));
+        let notified_clone = notified.clone();
+        let _guard = count.watch(move |_| *notified_clone.borrow_mut() = true);
+
+        source.set(1);
+        source.set(2);
+        assert_eq!(count.get(), 2);
+        assert!(!*notified.borrow());
+    }
+
+    #[test]
+    fn map_stateful_get_is_idempotent() {
+        let source: Binding<i32> = binding(10);
+        let delta = source.map_stateful(0, |last, value| value - last.replace(value));
+        assert_eq!(delta.get(), 10);
+        assert_eq!(delta.get(), 10);
+
+        let first: Rc<RefCell<Vec<i32>>> = Rc::default();
+        let second: Rc<RefCell<Vec<i32>>> = Rc::default();
+        let _first_guard = {
+            let first = first.clone();
+            delta.watch(move |ctx| first.borrow_mut().push(ctx.into_value()))
+        };
+        let _second_guard = {
+            let second = second.clone();
+            delta.watch(move |ctx| second.borrow_mut().push(ctx.into_value()))
+        };
+
+        source.set(15);
+        source.set(12);
+        assert_eq!(*first.borrow(), vec![5, -3]);
+        assert_eq!(*second.borrow(), vec![5, -3]);
+        assert_eq!(delta.get(), -3);
+    }
+}
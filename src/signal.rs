@@ -12,10 +12,16 @@
 
 mod computed;
 pub use computed::*;
+mod derived;
+pub use derived::{Derived, ErasedSignal};
+
+use core::{any::Any, cell::RefCell};
+
+use alloc::rc::Rc;
 
 use crate::{
     map::{Map, map},
-    watcher::Context,
+    watcher::{Context, OnDrop, WatcherManager, WatcherManagerGuard},
 };
 
 pub use nami_core::Signal;
@@ -109,3 +115,251 @@ impl<C: Signal, T: Clone + 'static> Signal for WithMetadata<C, T> {
 }
 
 impl_signal_wrapper_ops!(WithMetadata<C, T>, [C, T], C);
+
+/// A wrapper that attaches the notification time to every watcher context.
+///
+/// Watchers can read the time with `ctx.metadata().try_get::<Instant>()`.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct Timestamped<C> {
+    signal: C,
+}
+
+#[cfg(feature = "std")]
+impl<C> Timestamped<C> {
+    /// Create a new computation that timestamps its notifications.
+    pub const fn new(signal: C) -> Self {
+        Self { signal }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: Signal> Signal for Timestamped<C> {
+    type Output = C::Output;
+    type Guard = C::Guard;
+
+    /// Execute the underlying computation.
+    fn get(&self) -> Self::Output {
+        self.signal.get()
+    }
+
+    /// Register a watcher, attaching the current [`Instant`](std::time::Instant) to each notification.
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.signal
+            .watch(move |context: Context<<C as Signal>::Output>| {
+                watcher(context.with(std::time::Instant::now()));
+            })
+    }
+}
+
+#[cfg(feature = "std")]
+impl_signal_wrapper_ops!(Timestamped<C>, [C], C);
+
+/// A wrapper that runs a cleanup callback once the last clone is dropped.
+///
+/// Created by [`SignalExt::on_drop_signal`](crate::SignalExt::on_drop_signal).
+/// Watch guards do not keep the wrapper alive: the callback runs when the
+/// signal handles are gone, even if watchers are still registered.
+#[derive(Debug, Clone)]
+pub struct OnDropSignal<C> {
+    signal: C,
+    _cleanup: Rc<dyn Any>,
+}
+
+impl<C> OnDropSignal<C> {
+    /// Wraps `signal`, running `cleanup` when the wrapper and all its clones drop.
+    pub fn new(signal: C, cleanup: impl FnOnce() + 'static) -> Self {
+        Self {
+            signal,
+            _cleanup: Rc::new(OnDrop::new(cleanup)),
+        }
+    }
+}
+
+impl<C: Signal> Signal for OnDropSignal<C> {
+    type Output = C::Output;
+    type Guard = C::Guard;
+
+    fn get(&self) -> Self::Output {
+        self.signal.get()
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.signal.watch(watcher)
+    }
+}
+
+impl_signal_wrapper_ops!(OnDropSignal<C>, [C], C);
+
+/// A signal extracting a metadata value of type `M` from another signal's notifications.
+///
+/// Created by [`SignalExt::metadata_signal`](crate::SignalExt::metadata_signal).
+/// It subscribes to its source once and remembers the last `M` it saw, so
+/// [`get`](Signal::get) returns `None` until a notification carrying an `M`
+/// arrives. Watchers fire only for notifications carrying an `M`.
+pub struct MetadataSignal<C, M> {
+    signal: C,
+    last: Rc<RefCell<Option<M>>>,
+    watchers: WatcherManager<Option<M>>,
+    upstream: Rc<dyn Any>,
+}
+
+impl<C: core::fmt::Debug, M: core::fmt::Debug> core::fmt::Debug for MetadataSignal<C, M> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MetadataSignal")
+            .field("signal", &self.signal)
+            .field("last", &self.last)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C: Clone, M> Clone for MetadataSignal<C, M> {
+    fn clone(&self) -> Self {
+        Self {
+            signal: self.signal.clone(),
+            last: self.last.clone(),
+            watchers: self.watchers.clone(),
+            upstream: self.upstream.clone(),
+        }
+    }
+}
+
+impl<C: Signal, M: Clone + 'static> MetadataSignal<C, M> {
+    /// Observes the `M` metadata attached to `signal`'s notifications.
+    pub fn new(signal: C) -> Self {
+        let last = Rc::new(RefCell::new(None));
+        let watchers = WatcherManager::new();
+        let guard = {
+            let last = last.clone();
+            let watchers = watchers.clone();
+            signal.watch(move |context: Context<C::Output>| {
+                if let Some(metadata) = context.metadata().try_get::<M>() {
+                    last.replace(Some(metadata.clone()));
+                    watchers.notify(&Context::from(Some(metadata)));
+                }
+            })
+        };
+
+        Self {
+            signal,
+            last,
+            watchers,
+            upstream: Rc::new(guard),
+        }
+    }
+}
+
+impl<C: Signal, M: Clone + 'static> Signal for MetadataSignal<C, M> {
+    type Output = Option<M>;
+    type Guard = WatcherManagerGuard<Option<M>>;
+
+    fn get(&self) -> Self::Output {
+        self.last.borrow().clone()
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.watchers.register_as_guard(watcher)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{Binding, SignalExt, binding};
+    use alloc::vec::Vec;
+    use core::cell::RefCell;
+    use std::time::Instant;
+
+    #[test]
+    fn on_drop_signal_cleans_up_once_after_last_clone() {
+        let source: Binding<i32> = binding(1);
+        let cleanups = Rc::new(core::cell::Cell::new(0));
+        let wrapped = {
+            let cleanups = cleanups.clone();
+            source.on_drop_signal(move || cleanups.set(cleanups.get() + 1))
+        };
+        let clone = wrapped.clone();
+        assert_eq!(clone.get(), 1);
+
+        drop(wrapped);
+        assert_eq!(cleanups.get(), 0, "a clone is still alive");
+        drop(clone);
+        assert_eq!(cleanups.get(), 1);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct ScrollPosition(f32);
+
+    #[test]
+    fn metadata_signal_forwards_only_present_metadata() {
+        let source: Binding<i32> = binding(0);
+        let positions = source
+            .with(ScrollPosition(12.5))
+            .metadata_signal::<ScrollPosition>();
+        assert_eq!(positions.get(), None);
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = positions.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        let bare = source.metadata_signal::<ScrollPosition>();
+        let bare_seen = Rc::new(core::cell::Cell::new(0));
+        let bare_seen_clone = bare_seen.clone();
+        let _bare_guard = bare.watch(move |_| bare_seen_clone.set(bare_seen_clone.get() + 1));
+
+        source.set(1);
+        source.set(2);
+        assert_eq!(
+            *seen.borrow(),
+            [Some(ScrollPosition(12.5)), Some(ScrollPosition(12.5))]
+        );
+        assert_eq!(
+            bare_seen.get(),
+            0,
+            "notifications without the metadata are skipped"
+        );
+    }
+
+    #[test]
+    fn metadata_signal_get_returns_last_seen_metadata() {
+        let source: Binding<i32> = binding(0);
+        let positions = source
+            .with(ScrollPosition(3.0))
+            .metadata_signal::<ScrollPosition>();
+        let clone = positions.clone();
+        assert_eq!(positions.get(), None, "nothing has been notified yet");
+
+        source.set(1);
+        assert_eq!(positions.get(), Some(ScrollPosition(3.0)));
+        assert_eq!(
+            clone.get(),
+            Some(ScrollPosition(3.0)),
+            "clones share the cache"
+        );
+    }
+
+    #[test]
+    fn timestamps_are_present_and_monotonic() {
+        let source: Binding<i32> = binding(0);
+        let stamped = source.timestamped();
+
+        let stamps: Rc<RefCell<Vec<Option<Instant>>>> = Rc::default();
+        let stamps_clone = stamps.clone();
+        let _guard = stamped.watch(move |ctx| {
+            stamps_clone
+                .borrow_mut()
+                .push(ctx.metadata().try_get::<Instant>());
+        });
+
+        let before = Instant::now();
+        for value in 1..=3 {
+            source.set(value);
+        }
+
+        let stamps = stamps.borrow();
+        assert_eq!(stamps.len(), 3);
+        let stamps: Vec<Instant> = stamps.iter().map(|stamp| stamp.unwrap()).collect();
+        assert!(stamps[0] >= before);
+        assert!(stamps.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+}
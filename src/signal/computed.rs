@@ -3,7 +3,8 @@ use core::any::Any;
 use alloc::{boxed::Box, rc::Rc};
 
 use crate::{
-    SignalExt, constant,
+    SignalExt,
+    constant::{Constant, constant},
     watcher::{BoxWatcherGuard, Context, Watcher},
 };
 
@@ -72,6 +73,13 @@ impl<T> core::fmt::Debug for Computed<T> {
     }
 }
 
+/// Formats the current value of the computation.
+impl<T: core::fmt::Display + 'static> core::fmt::Display for Computed<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.get(), f)
+    }
+}
+
 /// Implements `Compute` for `Computed<T>`.
 ///
 /// This delegates to the internal boxed implementation.
@@ -104,13 +112,214 @@ impl<T> Computed<T> {
     {
         Self(Box::new(value))
     }
+
+    /// Returns a reference to the underlying signal if it is of type `S`.
+    ///
+    /// A signal erased with [`SignalExt::computed`] is stored as-is, so a
+    /// `Binding<T>` can be recovered as a `Binding<T>`. `Computed::from(binding)`
+    /// unwraps the binding instead, leaving its implementation (such as
+    /// [`Container<T>`](crate::Container)) as the stored type.
+    #[must_use]
+    pub fn downcast_ref<S>(&self) -> Option<&S>
+    where
+        S: Signal<Output = T> + 'static,
+        T: 'static,
+    {
+        let any = self.0.as_ref() as &dyn Any;
+        any.downcast_ref::<S>()
+    }
 }
 
 impl<T: 'static + Clone> Computed<T> {
     /// Creates a new constant computation with the provided value.
     ///
-    /// This is a convenience wrapper around `Computed::new(constant(value))`.
+    /// The [`Constant`] is stored directly behind the single box, so this is
+    /// as cheap as `Computed::new(constant(value))` and never wraps another
+    /// `Computed`.
     pub fn constant(value: T) -> Self {
         Self::new(constant(value))
     }
 }
+
+/// Erases a constant without adding a layer, as with [`Computed::constant`].
+impl<T: 'static + Clone> From<Constant<T>> for Computed<T> {
+    fn from(value: Constant<T>) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Combines a pair of signals into one computed value, as with [`zip`](crate::zip::zip).
+impl<A, B> From<(A, B)> for Computed<(A::Output, B::Output)>
+where
+    A: Signal,
+    B: Signal,
+    A::Output: Clone,
+    B::Output: Clone,
+{
+    fn from((a, b): (A, B)) -> Self {
+        Self::new(crate::zip::zip(a, b))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: 'static> Computed<T> {
+    /// Wraps this computation in an error boundary.
+    ///
+    /// A panic inside `get` is caught with [`std::panic::catch_unwind`] and
+    /// reported as `Err` carrying the panic message, so one failing node does
+    /// not take down the rest of the graph. Watchers receive notified values
+    /// as `Ok`. The panic hook still runs as usual.
+    #[must_use]
+    pub fn try_compute(self) -> Computed<Result<T, alloc::string::String>> {
+        Computed::new(TryCompute { source: self })
+    }
+}
+
+#[cfg(feature = "std")]
+struct TryCompute<T> {
+    source: Computed<T>,
+}
+
+#[cfg(feature = "std")]
+impl<T: 'static> Clone for TryCompute<T> {
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: 'static> Signal for TryCompute<T> {
+    type Output = Result<T, alloc::string::String>;
+    type Guard = BoxWatcherGuard;
+
+    fn get(&self) -> Self::Output {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| self.source.get())).map_err(
+            |payload| {
+                payload
+                    .downcast_ref::<&str>()
+                    .map(|message| (*message).into())
+                    .or_else(|| payload.downcast_ref::<alloc::string::String>().cloned())
+                    .unwrap_or_else(|| "computation panicked".into())
+            },
+        )
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.source.watch(move |ctx| watcher(ctx.map(Ok)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Binding, Container, CustomBinding, binding};
+    use alloc::format;
+
+    #[test]
+    fn display_formats_current_value() {
+        let source: Binding<i32> = binding(3);
+        let doubled = source.map(|value| value * 2).computed();
+        assert_eq!(format!("{doubled}"), "6");
+
+        source.set(21);
+        assert_eq!(format!("{doubled}"), "42");
+        assert_eq!(format!("{}", Computed::constant("done")), "done");
+    }
+
+    #[test]
+    fn constants_are_stored_without_extra_layer() {
+        let direct = Computed::constant(5);
+        let converted = Computed::from(constant(5));
+        assert_eq!(direct.get(), 5);
+        assert_eq!(converted.get(), 5);
+
+        assert!(direct.downcast_ref::<Constant<i32>>().is_some());
+        assert!(converted.downcast_ref::<Constant<i32>>().is_some());
+        let cloned = converted.clone();
+        drop(converted);
+        assert!(
+            cloned.downcast_ref::<Constant<i32>>().is_some(),
+            "cloning should not wrap the constant again"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn try_compute_reports_panics_as_errors() {
+        let divisor: Binding<i32> = binding(2);
+        let quotient = divisor
+            .map(|divisor| {
+                assert!(divisor != 0, "division by zero");
+                100 / divisor
+            })
+            .computed()
+            .try_compute();
+        assert_eq!(quotient.get(), Ok(50));
+
+        divisor.set(0);
+        assert_eq!(quotient.get(), Err("division by zero".into()));
+
+        divisor.set(4);
+        assert_eq!(quotient.get(), Ok(25));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn try_compute_forwards_notifications_as_ok() {
+        let source: Binding<i32> = binding(1);
+        let guarded = source.computed().try_compute();
+        let seen = Rc::new(core::cell::RefCell::new(alloc::vec::Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = guarded.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        source.set(2);
+        assert_eq!(*seen.borrow(), [Ok(2)]);
+    }
+
+    #[test]
+    fn tuple_of_signals_converts_into_computed() {
+        let name: Binding<&str> = binding("Ada");
+        let age: Binding<u32> = binding(36_u32);
+        let person: Computed<_> = (name.clone(), age.clone()).into();
+        assert_eq!(person.get(), ("Ada", 36));
+
+        let seen = Rc::new(core::cell::RefCell::new(alloc::vec::Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = person.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        age.set(37);
+        name.set("Grace");
+        assert_eq!(*seen.borrow(), [("Ada", 37), ("Grace", 37)]);
+    }
+
+    #[test]
+    fn downcast_recovers_binding() {
+        let source: Binding<i32> = binding(1);
+        let computed = source.computed();
+
+        let recovered = computed
+            .downcast_ref::<Binding<i32>>()
+            .expect("computed should hold a binding");
+        recovered.set(5);
+
+        assert_eq!(source.get(), 5);
+        assert_eq!(computed.get(), 5);
+        assert!(computed.downcast_ref::<Computed<i32>>().is_none());
+    }
+
+    #[test]
+    fn downcast_after_from_conversion_yields_container() {
+        let source: Binding<i32> = binding(1);
+        let computed = Computed::from(source.clone());
+
+        assert!(computed.downcast_ref::<Binding<i32>>().is_none());
+        let container = computed
+            .downcast_ref::<Container<i32>>()
+            .expect("conversion should keep the container");
+        container.set(7);
+
+        assert_eq!(source.get(), 7);
+    }
+}
@@ -0,0 +1,195 @@
+use alloc::{
+    boxed::Box,
+    rc::{Rc, Weak},
+    vec::Vec,
+};
+use core::cell::RefCell;
+
+use crate::watcher::{BoxWatcherGuard, Context, WatcherManager, WatcherManagerGuard, run_batched};
+
+use super::{Computed, Signal};
+
+/// An object-safe view of a signal that only reports that it changed.
+///
+/// Every [`Signal`] implements it, so signals of different output types can be
+/// listed together as the dependencies of [`Computed::derived`].
+pub trait ErasedSignal {
+    /// Calls `on_change` whenever the signal notifies, dropping the value.
+    fn watch_erased(&self, on_change: Box<dyn Fn()>) -> BoxWatcherGuard;
+}
+
+impl<S: Signal> ErasedSignal for S {
+    fn watch_erased(&self, on_change: Box<dyn Fn()>) -> BoxWatcherGuard {
+        Box::new(self.watch(move |_| on_change()))
+    }
+}
+
+/// A value recomputed from an explicit list of dependencies.
+///
+/// Created by [`Computed::derived`].
+pub struct Derived<T> {
+    inner: Rc<DerivedInner<T>>,
+}
+
+struct DerivedInner<T> {
+    value: RefCell<T>,
+    compute: Box<dyn Fn() -> T>,
+    watchers: WatcherManager<T>,
+    deps: RefCell<Vec<BoxWatcherGuard>>,
+}
+
+impl<T> core::fmt::Debug for Derived<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Derived").finish_non_exhaustive()
+    }
+}
+
+impl<T> Clone for Derived<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Clone + 'static> Derived<T> {
+    fn new(deps: &[&dyn ErasedSignal], compute: impl Fn() -> T + 'static) -> Self {
+        let inner = Rc::new(DerivedInner {
+            value: RefCell::new(compute()),
+            compute: Box::new(compute),
+            watchers: WatcherManager::new(),
+            deps: RefCell::default(),
+        });
+        let key = Rc::as_ptr(&inner).cast::<()>() as usize;
+        let guards = deps
+            .iter()
+            .map(|dep| {
+                let weak = Rc::downgrade(&inner);
+                dep.watch_erased(Box::new(move || {
+                    let weak = weak.clone();
+                    run_batched(key, move || recompute(&weak));
+                }))
+            })
+            .collect();
+        *inner.deps.borrow_mut() = guards;
+        Self { inner }
+    }
+}
+
+fn recompute<T: Clone + 'static>(inner: &Weak<DerivedInner<T>>) {
+    let Some(inner) = inner.upgrade() else {
+        return;
+    };
+    let value = (inner.compute)();
+    *inner.value.borrow_mut() = value.clone();
+    inner.watchers.notify(&Context::from(value));
+}
+
+impl<T: Clone + 'static> Signal for Derived<T> {
+    type Output = T;
+    type Guard = WatcherManagerGuard<T>;
+
+    fn get(&self) -> Self::Output {
+        self.inner.value.borrow().clone()
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.inner.watchers.register_as_guard(watcher)
+    }
+}
+
+impl<T: Clone + 'static> Computed<T> {
+    /// Creates a computed value that re-runs `compute` when any of `deps` changes.
+    ///
+    /// `compute` reads its inputs itself; `deps` only decides when it runs.
+    /// Inside [`batch`](crate::batch), changes to any number of dependencies
+    /// cause a single recompute once the batch ends, and derived values that
+    /// depend on other derived values recompute after them. Outside a batch,
+    /// each change recomputes immediately. Coalescing needs the `std` feature;
+    /// without it [`batch`](crate::batch) runs unbatched, so every change
+    /// recomputes.
+    ///
+    /// ```
+    /// use nami::{Binding, Computed, Signal, batch, binding};
+    ///
+    /// let width: Binding<u32> = binding(2_u32);
+    /// let height: Binding<u32> = binding(3_u32);
+    /// let area = Computed::derived(&[&width, &height], {
+    ///     let (width, height) = (width.clone(), height.clone());
+    ///     move || width.get() * height.get()
+    /// });
+    ///
+    /// batch(|| {
+    ///     width.set(4);
+    ///     height.set(5);
+    /// });
+    /// assert_eq!(area.get(), 20);
+    /// ```
+    pub fn derived(deps: &[&dyn ErasedSignal], compute: impl Fn() -> T + 'static) -> Self {
+        Self::new(Derived::new(deps, compute))
+    }
+}
+
+// Coalescing needs the `std` batch queue.
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{Binding, batch, binding};
+    use alloc::vec;
+    use core::cell::Cell;
+
+    #[test]
+    fn batched_dependency_changes_recompute_once() {
+        let a: Binding<i32> = binding(1);
+        let b: Binding<&str> = binding("x");
+        let c: Binding<bool> = binding(false);
+        let runs = Rc::new(Cell::new(0));
+        let summary = Computed::derived(&[&a, &b, &c], {
+            let (a, b, c, runs) = (a.clone(), b.clone(), c.clone(), runs.clone());
+            move || {
+                runs.set(runs.get() + 1);
+                alloc::format!("{}{}{}", a.get(), b.get(), c.get())
+            }
+        });
+        let seen: Rc<RefCell<Vec<alloc::string::String>>> = Rc::default();
+        let seen_clone = seen.clone();
+        let _guard = summary.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+        runs.set(0);
+
+        batch(|| {
+            a.set(2);
+            b.set("y");
+            c.set(true);
+        });
+
+        assert_eq!(runs.get(), 1);
+        assert_eq!(*seen.borrow(), vec!["2ytrue"]);
+    }
+
+    #[test]
+    fn diamond_recomputes_in_dependency_order() {
+        let source: Binding<i32> = binding(1);
+        let doubled = Computed::derived(&[&source], {
+            let source = source.clone();
+            move || source.get() * 2
+        });
+        let tripled = Computed::derived(&[&source], {
+            let source = source.clone();
+            move || source.get() * 3
+        });
+        let runs = Rc::new(Cell::new(0));
+        let total = Computed::derived(&[&doubled, &tripled], {
+            let (doubled, tripled, runs) = (doubled.clone(), tripled.clone(), runs.clone());
+            move || {
+                runs.set(runs.get() + 1);
+                doubled.get() + tripled.get()
+            }
+        });
+        runs.set(0);
+
+        batch(|| source.set(10));
+
+        assert_eq!(total.get(), 50);
+        assert_eq!(runs.get(), 1, "the sink should see both inputs updated");
+    }
+}
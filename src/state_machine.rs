@@ -0,0 +1,165 @@
+//! # State Machines
+//!
+//! This module provides [`StateMachine`], a binding whose value only changes
+//! through a transition function. It suits UI flows where some events are only
+//! meaningful in certain states and should otherwise be ignored.
+//!
+//! ```rust
+//! use nami::{Signal, binding};
+//! use nami::state_machine::StateMachine;
+//!
+//! #[derive(Clone, Copy, Debug, PartialEq)]
+//! enum Door { Open, Closed }
+//!
+//! let door = StateMachine::new(binding(Door::Closed), |state: &Door, push: bool| {
+//!     match (state, push) {
+//!         (Door::Closed, true) => Some(Door::Open),
+//!         (Door::Open, false) => Some(Door::Closed),
+//!         _ => None,
+//!     }
+//! });
+//!
+//! assert!(!door.transition(false));
+//! assert!(door.transition(true));
+//! assert_eq!(door.get(), Door::Open);
+//! ```
+
+use alloc::rc::Rc;
+use core::marker::PhantomData;
+
+use nami_core::watcher::Context;
+
+use crate::{Binding, Computed, Signal, SignalExt};
+
+/// A binding whose state changes only through guarded transitions.
+///
+/// The transition function receives the current state and an event, and
+/// returns the next state or `None` to reject the event. Rejected events leave
+/// the state untouched and notify no watchers.
+#[derive(Debug)]
+pub struct StateMachine<S: 'static, E, F> {
+    state: Binding<S>,
+    transition: Rc<F>,
+    _marker: PhantomData<fn(E)>,
+}
+
+impl<S: 'static, E, F> Clone for StateMachine<S, E, F> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            transition: self.transition.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, E, F> StateMachine<S, E, F>
+where
+    S: Clone + 'static,
+    F: Fn(&S, E) -> Option<S> + 'static,
+{
+    /// Creates a state machine driving `state` with the given transition function.
+    pub fn new(state: Binding<S>, transition: F) -> Self {
+        Self {
+            state,
+            transition: Rc::new(transition),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Applies `event` to the current state.
+    ///
+    /// Returns `true` if the transition was accepted and the state updated,
+    /// or `false` if the transition function rejected it.
+    pub fn transition(&self, event: E) -> bool {
+        let next = self.state.with_ref(|state| (self.transition)(state, event));
+        next.map(|next| self.state.set(next)).is_some()
+    }
+
+    /// Returns a read-only signal of the current state.
+    #[must_use]
+    pub fn state(&self) -> Computed<S> {
+        self.state.computed()
+    }
+}
+
+impl<S, E, F> Signal for StateMachine<S, E, F>
+where
+    S: Clone + 'static,
+    E: 'static,
+    F: 'static,
+{
+    type Output = S;
+    type Guard = <Binding<S> as Signal>::Guard;
+
+    fn get(&self) -> Self::Output {
+        self.state.get()
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.state.watch(watcher)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binding;
+    use alloc::{vec, vec::Vec};
+    use core::cell::RefCell;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum Light {
+        Red,
+        Green,
+        Yellow,
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    enum Event {
+        Go,
+        Slow,
+        Stop,
+    }
+
+    fn traffic_light() -> StateMachine<Light, Event, impl Fn(&Light, Event) -> Option<Light>> {
+        StateMachine::new(binding(Light::Red), |light: &Light, event| {
+            match (light, event) {
+                (Light::Red, Event::Go) => Some(Light::Green),
+                (Light::Green, Event::Slow) => Some(Light::Yellow),
+                (Light::Yellow, Event::Stop) => Some(Light::Red),
+                _ => None,
+            }
+        })
+    }
+
+    #[test]
+    fn follows_valid_transitions() {
+        let light = traffic_light();
+        assert!(light.transition(Event::Go));
+        assert!(light.transition(Event::Slow));
+        assert_eq!(light.get(), Light::Yellow);
+        assert!(light.transition(Event::Stop));
+        assert_eq!(light.state().get(), Light::Red);
+    }
+
+    #[test]
+    fn rejected_transitions_do_not_notify() {
+        let light = traffic_light();
+        let seen: Rc<RefCell<Vec<Light>>> = Rc::default();
+        let _guard = {
+            let seen = seen.clone();
+            light
+                .state()
+                .watch(move |ctx| seen.borrow_mut().push(ctx.into_value()))
+        };
+
+        assert!(!light.transition(Event::Slow));
+        assert!(!light.transition(Event::Stop));
+        assert_eq!(light.get(), Light::Red);
+
+        assert!(light.transition(Event::Go));
+        assert!(!light.transition(Event::Go));
+        assert_eq!(*seen.borrow(), vec![Light::Green]);
+    }
+}
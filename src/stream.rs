@@ -6,6 +6,8 @@
 //!   `Signal<Output = Option<S::Item>>`.
 //! - `SignalStream<S>`: expose a `Signal<Output = T>` as a
 //!   `Stream<Item = T>` that yields on updates.
+//! - `Changes<T>`: an awaitable handle over a signal's updates, meant for
+//!   `while let Some(value) = changes.next().await` loops.
 //!
 //! These adapters are useful when bridging async event sources with
 //! reactive computations, or when a consumer expects a `Stream` API.
@@ -20,7 +22,9 @@ use core::{
 use futures_core::Stream;
 use pin_project_lite::pin_project;
 
-use crate::{Container, Signal};
+use alloc::boxed::Box;
+
+use crate::{Container, Signal, watcher::BoxWatcherGuard};
 
 /// A `Signal` backed by a stream that holds the latest item.
 ///
@@ -115,3 +119,102 @@ impl<S: Signal> Stream for SignalStream<S> {
             .map(Result::ok)
     }
 }
+
+pin_project! {
+    /// A repeatedly awaitable sequence of a signal's changes.
+    ///
+    /// Created by [`SignalExt::changes`](crate::SignalExt::changes). Unlike
+    /// [`SignalStream`], which subscribes on its first poll, `Changes` subscribes
+    /// as soon as it is created, so no update is missed before the first `await`.
+    /// Updates are buffered until they are consumed, and the subscription is
+    /// released when the handle is dropped.
+    pub struct Changes<T> {
+        #[pin]
+        receiver: async_channel::Receiver<T>,
+        guard: BoxWatcherGuard,
+    }
+}
+
+impl<T> core::fmt::Debug for Changes<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Changes")
+            .field("pending", &self.receiver.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: 'static> Changes<T> {
+    /// Subscribes to the changes of `signal`.
+    pub fn new<S>(signal: &S) -> Self
+    where
+        S: Signal<Output = T>,
+    {
+        let (sender, receiver) = async_channel::unbounded();
+        let guard = signal.watch(move |ctx| {
+            let _ = sender.try_send(ctx.into_value());
+        });
+        Self {
+            receiver,
+            guard: Box::new(guard),
+        }
+    }
+
+    /// Waits for the next change.
+    ///
+    /// Returns `None` only if the source's watcher list was torn down.
+    #[allow(clippy::future_not_send)]
+    pub async fn next(&mut self) -> Option<T> {
+        self.receiver.recv().await.ok()
+    }
+}
+
+impl<T> Stream for Changes<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().receiver.poll_next(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Binding, SignalExt, binding};
+    use alloc::{vec, vec::Vec};
+    use core::task::Waker;
+
+    fn poll_once<F: Future>(future: F) -> Poll<F::Output> {
+        pin!(future).poll(&mut Context::from_waker(Waker::noop()))
+    }
+
+    #[test]
+    fn changes_yield_every_update_in_order() {
+        let source: Binding<i32> = binding(0);
+        let mut changes = source.changes();
+
+        source.set(1);
+        source.set(2);
+        source.set(3);
+
+        let mut seen = Vec::new();
+        while let Poll::Ready(Some(value)) = poll_once(changes.next()) {
+            seen.push(value);
+        }
+        assert_eq!(seen, vec![1, 2, 3]);
+
+        assert!(poll_once(changes.next()).is_pending());
+        source.set(4);
+        assert_eq!(poll_once(changes.next()), Poll::Ready(Some(4)));
+    }
+
+    #[test]
+    fn changes_skip_values_set_before_subscribing() {
+        let source: Binding<i32> = binding(0);
+        source.set(1);
+        let mut changes = source.changes();
+
+        assert!(poll_once(changes.next()).is_pending());
+        source.set(2);
+        assert_eq!(poll_once(changes.next()), Poll::Ready(Some(2)));
+    }
+}
@@ -0,0 +1,99 @@
+//! # Testing Utilities
+//!
+//! Helpers for asserting on the values emitted by reactive pipelines and,
+//! together with the `timer` feature, for driving timer-based signals such as
+//! debounce and throttle deterministically. Enabled with the `testing` feature.
+
+use alloc::{rc::Rc, vec::Vec};
+use core::cell::RefCell;
+
+use crate::Signal;
+
+#[cfg(feature = "timer")]
+mod manual_timer;
+#[cfg(feature = "timer")]
+pub use manual_timer::ManualTimer;
+#[cfg(feature = "timer")]
+pub(crate) use manual_timer::{Clock, clock_of};
+
+/// Collects every value a signal emits to its watchers.
+///
+/// Created by [`SignalExt::record`](crate::SignalExt::record). Recording stops
+/// once the guard returned alongside the recorder is dropped. Clones share the
+/// same log.
+#[derive(Debug)]
+pub struct Recorder<T> {
+    values: Rc<RefCell<Vec<T>>>,
+}
+
+impl<T> Clone for Recorder<T> {
+    fn clone(&self) -> Self {
+        Self {
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl<T: 'static> Recorder<T> {
+    /// Starts recording the notifications of `signal`.
+    ///
+    /// The initial value is not recorded, only subsequent changes.
+    pub fn new<S>(signal: &S) -> (Self, S::Guard)
+    where
+        S: Signal<Output = T>,
+    {
+        let values: Rc<RefCell<Vec<T>>> = Rc::default();
+        let guard = {
+            let values = values.clone();
+            signal.watch(move |ctx| values.borrow_mut().push(ctx.into_value()))
+        };
+        (Self { values }, guard)
+    }
+
+    /// Returns the values recorded so far, oldest first.
+    #[must_use]
+    pub fn values(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.values.borrow().clone()
+    }
+
+    /// Removes and returns the values recorded so far.
+    #[must_use]
+    pub fn take(&self) -> Vec<T> {
+        self.values.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Binding, SignalExt, binding};
+    use alloc::vec;
+
+    #[test]
+    fn recorder_stops_after_guard_drop() {
+        let source: Binding<i32> = binding(0);
+        let (recorder, guard) = source.record();
+
+        source.set(1);
+        assert_eq!(recorder.take(), vec![1]);
+        source.set(2);
+        drop(guard);
+        source.set(3);
+
+        assert_eq!(recorder.values(), vec![2]);
+    }
+
+    #[test]
+    fn recorder_sees_derived_values() {
+        let source: Binding<i32> = binding(1);
+        let doubled = source.map(|value| value * 2);
+        let (recorder, _guard) = doubled.record();
+
+        source.set(2);
+        source.set(5);
+
+        assert_eq!(recorder.values(), vec![4, 10]);
+    }
+}
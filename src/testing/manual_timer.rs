@@ -0,0 +1,289 @@
+use alloc::{collections::VecDeque, rc::Rc, sync::Arc, vec::Vec};
+use core::{
+    any::Any,
+    cell::{Cell, RefCell},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+use std::{
+    sync::{Mutex, PoisonError},
+    time::Instant,
+};
+
+use executor_core::{
+    LocalExecutor,
+    async_task::{AsyncTask, Runnable, spawn_local},
+};
+
+/// Returns the clock of `executor` if it is a [`ManualTimer`].
+pub fn clock_of<E: 'static>(executor: &E) -> Option<Rc<Clock>> {
+    (executor as &dyn Any)
+        .downcast_ref::<ManualTimer>()
+        .map(|timer| timer.clock.clone())
+}
+
+/// Virtual time shared by a [`ManualTimer`] and the sleeps it controls.
+#[derive(Debug)]
+pub struct Clock {
+    start: Instant,
+    elapsed: Cell<Duration>,
+    sleepers: RefCell<Vec<(Duration, Waker)>>,
+}
+
+impl Clock {
+    /// Returns the virtual current instant.
+    pub fn now(&self) -> Instant {
+        self.start + self.elapsed.get()
+    }
+
+    /// Completes once the virtual time has advanced by `duration`.
+    pub fn sleep(self: Rc<Self>, duration: Duration) -> Sleep {
+        Sleep {
+            deadline: self.elapsed.get() + duration,
+            clock: self,
+        }
+    }
+
+    fn next_deadline(&self) -> Option<Duration> {
+        self.sleepers
+            .borrow()
+            .iter()
+            .map(|(deadline, _)| *deadline)
+            .min()
+    }
+
+    fn wake_due(&self) {
+        let now = self.elapsed.get();
+        let due: Vec<Waker> = {
+            let mut sleepers = self.sleepers.borrow_mut();
+            let (due, pending) = sleepers
+                .drain(..)
+                .partition(|(deadline, _)| *deadline <= now);
+            *sleepers = pending;
+            due.into_iter().map(|(_, waker)| waker).collect()
+        };
+        for waker in due {
+            waker.wake();
+        }
+    }
+}
+
+/// A future completing at a deadline on a [`Clock`].
+#[derive(Debug)]
+pub struct Sleep {
+    clock: Rc<Clock>,
+    deadline: Duration,
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.clock.elapsed.get() >= self.deadline {
+            return Poll::Ready(());
+        }
+        self.clock
+            .sleepers
+            .borrow_mut()
+            .push((self.deadline, cx.waker().clone()));
+        Poll::Pending
+    }
+}
+
+/// A single-threaded executor with a manually advanced clock.
+///
+/// Timer-based signals (such as [`Debounce`](crate::debounce::Debounce) and
+/// [`Throttle`](crate::throttle::Throttle)) that are given a `ManualTimer` as
+/// their executor measure time on its clock instead of the system clock. Call
+/// [`advance`](Self::advance) to run them deterministically, without sleeping.
+/// Signals on other executors keep using the system clock.
+///
+/// ```
+/// use core::time::Duration;
+/// use nami::{Binding, binding, debounce::Debounce, testing::ManualTimer, SignalExt};
+///
+/// let timer = ManualTimer::new();
+/// let query: Binding<i32> = binding(0);
+/// let debounced = Debounce::with_executor(query.clone(), Duration::from_millis(300), timer.clone());
+/// let (recorder, _guard) = debounced.record();
+///
+/// query.set(1);
+/// timer.advance(Duration::from_millis(299));
+/// assert!(recorder.values().is_empty());
+/// timer.advance(Duration::from_millis(1));
+/// assert_eq!(recorder.values(), vec![1]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ManualTimer {
+    clock: Rc<Clock>,
+    queue: Arc<Mutex<VecDeque<Runnable>>>,
+}
+
+impl Default for ManualTimer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ManualTimer {
+    /// Creates a timer at virtual time zero.
+    ///
+    /// Each timer has its own clock; clones share it.
+    #[must_use]
+    pub fn new() -> Self {
+        let clock = Rc::new(Clock {
+            start: Instant::now(),
+            elapsed: Cell::new(Duration::ZERO),
+            sleepers: RefCell::default(),
+        });
+        Self {
+            clock,
+            queue: Arc::default(),
+        }
+    }
+
+    /// Returns how much virtual time has passed since the timer was created.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.clock.elapsed.get()
+    }
+
+    /// Runs every task that is ready, without advancing time.
+    pub fn run_until_idle(&self) {
+        loop {
+            let next = self
+                .queue
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .pop_front();
+            let Some(runnable) = next else {
+                break;
+            };
+            runnable.run();
+        }
+    }
+
+    /// Advances virtual time by `duration`, running tasks as their timers fire.
+    ///
+    /// Timers fire in deadline order, and tasks woken by one timer run before
+    /// time moves on to the next deadline.
+    pub fn advance(&self, duration: Duration) {
+        let target = self.clock.elapsed.get() + duration;
+        self.run_until_idle();
+        while let Some(deadline) = self
+            .clock
+            .next_deadline()
+            .filter(|deadline| *deadline <= target)
+        {
+            self.clock
+                .elapsed
+                .set(deadline.max(self.clock.elapsed.get()));
+            self.clock.wake_due();
+            self.run_until_idle();
+        }
+        self.clock.elapsed.set(target);
+    }
+}
+
+impl LocalExecutor for ManualTimer {
+    type Task<T: 'static> = AsyncTask<T>;
+
+    fn spawn_local<Fut>(&self, fut: Fut) -> Self::Task<Fut::Output>
+    where
+        Fut: Future + 'static,
+    {
+        let queue = self.queue.clone();
+        let (runnable, task) = spawn_local(fut, move |runnable| {
+            queue
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push_back(runnable);
+        });
+        runnable.schedule();
+        task
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Binding, SignalExt, binding, debounce::Debounce, throttle::Throttle};
+    use alloc::vec;
+
+    const STEP: Duration = Duration::from_millis(100);
+
+    #[test]
+    fn advancing_fires_debounce_deterministically() {
+        let timer = ManualTimer::new();
+        let source: Binding<i32> = binding(0);
+        let debounced = Debounce::with_executor(source.clone(), STEP, timer.clone());
+        let (recorder, _guard) = debounced.record();
+
+        source.set(1);
+        timer.advance(STEP / 2);
+        source.set(2);
+        timer.advance(STEP / 2);
+        assert!(
+            recorder.values().is_empty(),
+            "the second update restarted the wait"
+        );
+
+        timer.advance(STEP / 2);
+        assert_eq!(recorder.values(), vec![2]);
+        assert_eq!(timer.elapsed(), STEP * 3 / 2);
+    }
+
+    #[test]
+    fn max_wait_uses_virtual_time() {
+        let timer = ManualTimer::new();
+        let source: Binding<i32> = binding(0);
+        let debounced = Debounce::builder(source.clone())
+            .duration(STEP)
+            .max_wait(STEP * 2)
+            .executor(timer.clone())
+            .build();
+        let (recorder, _guard) = debounced.record();
+
+        for value in 1..=5 {
+            source.set(value);
+            timer.advance(STEP / 2);
+        }
+
+        assert_eq!(recorder.values(), vec![4]);
+    }
+
+    #[test]
+    fn timers_keep_separate_clocks() {
+        let outer = ManualTimer::new();
+        drop(ManualTimer::new());
+        let source: Binding<i32> = binding(0);
+        let debounced = Debounce::with_executor(source.clone(), STEP, outer.clone());
+        let (recorder, _guard) = debounced.record();
+
+        let inner = ManualTimer::new();
+        source.set(1);
+        inner.advance(STEP);
+        assert!(recorder.values().is_empty(), "only the outer clock counts");
+
+        outer.advance(STEP);
+        assert_eq!(recorder.values(), vec![1]);
+    }
+
+    #[test]
+    fn trailing_throttle_flushes_after_period() {
+        let timer = ManualTimer::new();
+        let source: Binding<i32> = binding(0);
+        let throttled = Throttle::with_executor(source.clone(), STEP, timer.clone()).trailing();
+        let (recorder, _guard) = throttled.record();
+
+        source.set(1);
+        source.set(2);
+        source.set(3);
+        assert_eq!(recorder.values(), vec![1]);
+
+        timer.advance(STEP);
+        assert_eq!(recorder.values(), vec![1, 3]);
+    }
+}
@@ -9,7 +9,7 @@ use nami_core::watcher::Context;
 
 use crate::{
     Signal,
-    utils::sleep,
+    utils::TimeSource,
     watcher::{WatcherManager, WatcherManagerGuard},
 };
 
@@ -17,6 +17,9 @@ use crate::{
 ///
 /// Unlike debounce, throttle emits the first update immediately and then limits subsequent
 /// updates until the throttle period expires.
+///
+/// Clones share the timer, downstream watchers, and upstream subscription. Whichever
+/// clone is watched first establishes the subscription, so clones may be watched in any order.
 pub struct Throttle<S, E>
 where
     S: Signal,
@@ -28,8 +31,13 @@ where
     timer: Rc<RefCell<Option<Box<dyn Task<()>>>>>,
     guard: Rc<RefCell<Option<S::Guard>>>,
     throttled: Rc<Cell<bool>>,
+    trailing: bool,
+    pending: Rc<RefCell<Option<Flush>>>,
 }
 
+/// A deferred delivery of a trailing value to downstream watchers.
+type Flush = Box<dyn FnOnce()>;
+
 impl<S, E> Debug for Throttle<S, E>
 where
     S: Signal + Debug,
@@ -41,6 +49,7 @@ where
             .field("duration", &self.duration)
             .field("watchers", &"<...>")
             .field("executor", &self.executor)
+            .field("trailing", &self.trailing)
             .finish_non_exhaustive()
     }
 }
@@ -59,6 +68,8 @@ where
             timer: self.timer.clone(),
             guard: self.guard.clone(),
             throttled: self.throttled.clone(),
+            trailing: self.trailing,
+            pending: self.pending.clone(),
         }
     }
 }
@@ -78,8 +89,20 @@ where
             timer: Rc::default(),
             guard: Rc::default(),
             throttled: Rc::default(),
+            trailing: false,
+            pending: Rc::default(),
         }
     }
+
+    /// Also emits the last value received during a throttle period once it ends.
+    ///
+    /// If the last clone of a trailing throttle is dropped while a value is
+    /// pending, that value is delivered to watchers synchronously during drop.
+    #[must_use]
+    pub const fn trailing(mut self) -> Self {
+        self.trailing = true;
+        self
+    }
 }
 
 impl<S> Throttle<S, DefaultExecutor>
@@ -111,17 +134,23 @@ where
         let executor = self.executor.clone();
         let timer = self.timer.clone();
         let throttled = self.throttled.clone();
+        let trailing = self.trailing;
+        let pending = self.pending.clone();
         let duration = self.duration;
 
         // Ensure we only set up the upstream watcher once
         let _signal_guard = self.guard.borrow_mut().get_or_insert_with(|| {
             signal.watch(move |ctx| {
-                // If we're currently throttled, ignore this update
-                if throttled.get() {
+                if watchers.is_empty() {
                     return;
                 }
 
-                if watchers.is_empty() {
+                // While throttled, keep only the latest value for trailing delivery
+                if throttled.get() {
+                    if trailing {
+                        let watchers = watchers.clone();
+                        *pending.borrow_mut() = Some(Box::new(move || watchers.notify(&ctx)));
+                    }
                     return;
                 }
 
@@ -132,8 +161,17 @@ where
                 throttled.set(true);
 
                 let throttled = throttled.clone();
+                let pending = pending.clone();
+                let time = TimeSource::of(&executor);
                 let task = executor.spawn_local(async move {
-                    sleep(duration).await;
+                    loop {
+                        time.sleep(duration).await;
+                        // A trailing value starts a new throttle period
+                        let Some(flush) = pending.borrow_mut().take() else {
+                            break;
+                        };
+                        flush();
+                    }
                     // Reset throttled state after the duration
                     throttled.set(false);
                 });
@@ -145,3 +183,104 @@ where
         self.watchers.register_as_guard(watcher)
     }
 }
+
+impl<S, E> Drop for Throttle<S, E>
+where
+    S: Signal,
+{
+    fn drop(&mut self) {
+        // Only the last clone flushes; `guard` is shared by clones alone.
+        if Rc::strong_count(&self.guard) != 1 {
+            return;
+        }
+        let flush = self.pending.borrow_mut().take();
+        if let Some(flush) = flush {
+            flush();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Binding, binding, testing::ManualTimer};
+    use alloc::{vec, vec::Vec};
+
+    const PERIOD: Duration = Duration::from_millis(20);
+
+    fn record(signal: &impl Signal<Output = i32>) -> (Rc<RefCell<Vec<i32>>>, impl Sized) {
+        let seen: Rc<RefCell<Vec<i32>>> = Rc::default();
+        let seen_clone = seen.clone();
+        let guard = signal.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+        (seen, guard)
+    }
+
+    #[test]
+    fn throttle_emits_leading_value_per_period() {
+        let timer = ManualTimer::new();
+        let source: Binding<i32> = binding(0);
+        let throttled = Throttle::with_executor(source.clone(), PERIOD, timer.clone());
+        let (seen, _guard) = record(&throttled);
+
+        source.set(1);
+        source.set(2);
+        timer.advance(PERIOD * 3);
+        source.set(3);
+
+        assert_eq!(*seen.borrow(), vec![1, 3]);
+    }
+
+    #[test]
+    fn trailing_throttle_emits_last_value_after_period() {
+        let timer = ManualTimer::new();
+        let source: Binding<i32> = binding(0);
+        let throttled = Throttle::with_executor(source.clone(), PERIOD, timer.clone()).trailing();
+        let (seen, _guard) = record(&throttled);
+
+        source.set(1);
+        source.set(2);
+        source.set(3);
+        timer.advance(PERIOD * 4);
+
+        assert_eq!(*seen.borrow(), vec![1, 3]);
+    }
+
+    #[test]
+    fn dropping_last_clone_flushes_pending_value() {
+        let timer = ManualTimer::new();
+        let source: Binding<i32> = binding(0);
+        let throttled = Throttle::with_executor(source.clone(), PERIOD, timer).trailing();
+        let seen: Rc<RefCell<Vec<i32>>> = Rc::default();
+        let seen_clone = seen.clone();
+        let _guard = throttled.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        source.set(1);
+        source.set(2);
+        let clone = throttled.clone();
+        drop(throttled);
+        assert_eq!(
+            *seen.borrow(),
+            vec![1],
+            "a remaining clone keeps the value pending"
+        );
+
+        drop(clone);
+        assert_eq!(*seen.borrow(), vec![1, 2]);
+    }
+
+    #[test]
+    fn clone_watched_before_original_shares_subscription() {
+        let timer = ManualTimer::new();
+        let source: Binding<i32> = binding(0);
+        let original = Throttle::with_executor(source.clone(), PERIOD, timer);
+        let clone = original.clone();
+
+        let (from_clone, _clone_guard) = record(&clone);
+        let (from_original, _original_guard) = record(&original);
+
+        source.set(5);
+
+        assert_eq!(*from_clone.borrow(), vec![5]);
+        assert_eq!(*from_original.borrow(), vec![5]);
+    }
+}
@@ -7,6 +7,7 @@
 //! The addition is performed using the standard `Add` trait from Rust's core library,
 //! allowing for flexible addition semantics depending on the types involved.
 
+use alloc::{string::String, vec::Vec};
 use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Rem, Shl, Shr, Sub};
 
 use crate::{
@@ -138,16 +139,219 @@ where
     map(zip, |(a, b)| core::cmp::min(a, b))
 }
 
+/// Selects a message for a count signal using plural rules.
+///
+/// `rules` maps a count to the string to display, typically one per plural
+/// category of the target language. The result updates whenever `count` does.
+///
+/// # Examples
+///
+/// ```
+/// # use nami::{Signal, utils::pluralize, binding, Binding};
+/// let unread: Binding<u64> = binding(1_u64);
+/// let label = pluralize(unread.clone(), |n| if n == 1 { "message" } else { "messages" });
+/// assert_eq!(label.get(), "message");
+/// unread.set(3);
+/// assert_eq!(label.get(), "messages");
+/// ```
+pub const fn pluralize<S, F>(count: S, rules: F) -> Map<S, F, &'static str>
+where
+    S: Signal<Output = u64>,
+    F: Fn(u64) -> &'static str + Clone + 'static,
+{
+    map(count, rules)
+}
+
+/// Splits `text` into segments flagged by whether they match `query`.
+///
+/// Matches are case-sensitive and non-overlapping, scanning left to right.
+/// An empty query matches nothing, and an empty text yields no segments.
+/// The result updates whenever either signal changes.
+///
+/// # Examples
+///
+/// ```
+/// # use nami::{Signal, utils::highlight, binding, Binding};
+/// let text: Binding<String> = binding("a cat and a cap");
+/// let query: Binding<String> = binding("ca");
+/// let segments = highlight(text, query.clone());
+/// assert_eq!(
+///     segments.get(),
+///     vec![
+///         ("a ".to_string(), false),
+///         ("ca".to_string(), true),
+///         ("t and a ".to_string(), false),
+///         ("ca".to_string(), true),
+///         ("p".to_string(), false),
+///     ]
+/// );
+/// ```
+pub fn highlight<T, Q>(text: T, query: Q) -> Map<Zip<T, Q>, HighlightFn, Vec<(String, bool)>>
+where
+    T: Signal<Output = String>,
+    Q: Signal<Output = String>,
+{
+    map(zip(text, query), |(text, query)| {
+        highlight_segments(&text, &query)
+    })
+}
+
+/// The transformation applied by [`highlight`].
+pub type HighlightFn = fn((String, String)) -> Vec<(String, bool)>;
+
+fn highlight_segments(text: &str, query: &str) -> Vec<(String, bool)> {
+    let mut segments = Vec::new();
+    if query.is_empty() {
+        if !text.is_empty() {
+            segments.push((text.into(), false));
+        }
+        return segments;
+    }
+    let mut rest = text;
+    while let Some(start) = rest.find(query) {
+        if start > 0 {
+            segments.push((rest[..start].into(), false));
+        }
+        let end = start + query.len();
+        segments.push((rest[start..end].into(), true));
+        rest = &rest[end..];
+    }
+    if !rest.is_empty() {
+        segments.push((rest.into(), false));
+    }
+    segments
+}
+
+/// Where a timer-based signal reads the time and sleeps.
+///
+/// Chosen from the signal's executor: a [`ManualTimer`](crate::testing::ManualTimer)
+/// supplies its virtual clock, anything else uses the system clock.
+#[cfg(feature = "timer")]
+#[derive(Debug, Clone)]
+pub(crate) struct TimeSource {
+    #[cfg(any(test, feature = "testing"))]
+    clock: Option<alloc::rc::Rc<crate::testing::Clock>>,
+}
+
 #[cfg(feature = "timer")]
-pub(crate) async fn sleep(duration: core::time::Duration) {
-    #[cfg(target_arch = "wasm32")]
-    {
-        use gloo_timers::future::sleep;
-        sleep(duration).await;
+impl TimeSource {
+    /// Returns the time source for signals running on `executor`.
+    #[cfg_attr(
+        not(any(test, feature = "testing")),
+        allow(clippy::missing_const_for_fn)
+    )]
+    pub(crate) fn of<E: 'static>(executor: &E) -> Self {
+        #[cfg(not(any(test, feature = "testing")))]
+        let _ = executor;
+        Self {
+            #[cfg(any(test, feature = "testing"))]
+            clock: crate::testing::clock_of(executor),
+        }
+    }
+
+    /// Returns the current instant.
+    #[cfg_attr(not(any(test, feature = "testing")), allow(clippy::unused_self))]
+    pub(crate) fn now(&self) -> std::time::Instant {
+        #[cfg(any(test, feature = "testing"))]
+        if let Some(clock) = &self.clock {
+            return clock.now();
+        }
+        std::time::Instant::now()
+    }
+
+    /// Completes once `duration` has passed.
+    #[cfg_attr(any(test, feature = "testing"), allow(clippy::future_not_send))]
+    pub(crate) async fn sleep(&self, duration: core::time::Duration) {
+        #[cfg(any(test, feature = "testing"))]
+        if let Some(clock) = &self.clock {
+            clock.clone().sleep(duration).await;
+            return;
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            use gloo_timers::future::sleep;
+            sleep(duration).await;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            use async_io::Timer;
+            Timer::after(duration).await;
+        }
     }
-    #[cfg(not(target_arch = "wasm32"))]
-    {
-        use async_io::Timer;
-        Timer::after(duration).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Binding, binding};
+
+    fn english(count: u64) -> &'static str {
+        match count {
+            0 => "no items",
+            1 => "one item",
+            _ => "many items",
+        }
+    }
+
+    #[test]
+    fn pluralize_follows_count() {
+        let count: Binding<u64> = binding(0_u64);
+        let label = pluralize(count.clone(), english);
+        assert_eq!(label.get(), "no items");
+
+        let seen = alloc::rc::Rc::new(core::cell::RefCell::new(alloc::vec::Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = label.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        count.set(1);
+        count.set(7);
+        assert_eq!(*seen.borrow(), ["one item", "many items"]);
+    }
+
+    fn segments(parts: &[(&str, bool)]) -> Vec<(String, bool)> {
+        parts
+            .iter()
+            .map(|&(text, matched)| (text.into(), matched))
+            .collect()
+    }
+
+    #[test]
+    fn highlight_marks_matches_and_follows_both_inputs() {
+        let text: Binding<String> = binding("nanami");
+        let query: Binding<String> = binding("na");
+        let highlighted = highlight(text.clone(), query.clone());
+        assert_eq!(
+            highlighted.get(),
+            segments(&[("na", true), ("na", true), ("mi", false)])
+        );
+
+        query.set("am".into());
+        assert_eq!(
+            highlighted.get(),
+            segments(&[("nan", false), ("am", true), ("i", false)])
+        );
+
+        text.set("Amami".into());
+        assert_eq!(
+            highlighted.get(),
+            segments(&[("Am", false), ("am", true), ("i", false)])
+        );
+    }
+
+    #[test]
+    fn highlight_edge_cases() {
+        let text: Binding<String> = binding("aaa");
+        let query: Binding<String> = binding("");
+        let highlighted = highlight(text.clone(), query.clone());
+        assert_eq!(highlighted.get(), segments(&[("aaa", false)]));
+
+        query.set("aa".into());
+        assert_eq!(highlighted.get(), segments(&[("aa", true), ("a", false)]));
+
+        query.set("x".into());
+        assert_eq!(highlighted.get(), segments(&[("aaa", false)]));
+
+        text.set(String::new());
+        assert!(highlighted.get().is_empty());
     }
 }
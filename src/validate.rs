@@ -0,0 +1,142 @@
+//! # Validated Bindings
+//!
+//! This module provides [`Validated`], a binding paired with a validator.
+//! It is designed for form fields, where the UI needs both the editable value
+//! and a reactive view of whether that value is currently valid.
+//!
+//! ```rust
+//! use nami::{Signal, binding};
+//! use nami::validate::Validated;
+//!
+//! let age = Validated::new(binding(20), |value: &i32| {
+//!     if *value >= 18 { Ok(()) } else { Err("too young".into()) }
+//! });
+//!
+//! assert!(age.is_valid().get());
+//! age.value().set(12);
+//! assert_eq!(age.error().get().as_deref(), Some("too young"));
+//! ```
+
+use alloc::{rc::Rc, string::String};
+
+use crate::{Binding, map::Map};
+
+/// A binding paired with a validator producing reactive error and validity signals.
+///
+/// The validator is re-run whenever the value changes, so [`Validated::error`] and
+/// [`Validated::is_valid`] always reflect the current value.
+#[derive(Debug)]
+pub struct Validated<T: 'static, F> {
+    value: Binding<T>,
+    validator: Rc<F>,
+}
+
+impl<T: 'static, F> Clone for Validated<T, F> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            validator: self.validator.clone(),
+        }
+    }
+}
+
+impl<T, F> Validated<T, F>
+where
+    T: Clone + 'static,
+    F: Fn(&T) -> Result<(), String> + 'static,
+{
+    /// Creates a new validated binding from a value binding and a validator.
+    pub fn new(value: Binding<T>, validator: F) -> Self {
+        Self {
+            value,
+            validator: Rc::new(validator),
+        }
+    }
+
+    /// Returns the underlying value binding.
+    #[must_use]
+    pub const fn value(&self) -> &Binding<T> {
+        &self.value
+    }
+
+    /// Validates the current value.
+    ///
+    /// # Errors
+    ///
+    /// Returns the validator's error message if the current value is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        (self.validator)(&self.value.get())
+    }
+
+    /// Returns a signal yielding the validation error for the current value, if any.
+    #[must_use]
+    pub fn error(
+        &self,
+    ) -> Map<Binding<T>, impl Fn(T) -> Option<String> + Clone + 'static, Option<String>> {
+        let validator = self.validator.clone();
+        Map::new(self.value.clone(), move |value: T| validator(&value).err())
+    }
+
+    /// Returns a signal yielding `true` while the current value is valid.
+    #[must_use]
+    pub fn is_valid(&self) -> Map<Binding<T>, impl Fn(T) -> bool + Clone + 'static, bool> {
+        let validator = self.validator.clone();
+        Map::new(self.value.clone(), move |value: T| {
+            validator(&value).is_ok()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Signal, binding};
+    use alloc::{string::ToString, vec::Vec};
+    use core::cell::RefCell;
+
+    fn non_empty<S: AsRef<str>>(value: &S) -> Result<(), String> {
+        if value.as_ref().is_empty() {
+            Err("required".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn validity_follows_value() {
+        let field = Validated::new(binding(String::from("nami")), non_empty::<String>);
+        assert!(field.is_valid().get());
+        assert_eq!(field.error().get(), None);
+        assert_eq!(field.validate(), Ok(()));
+
+        field.value().set(String::new());
+        assert!(!field.is_valid().get());
+        assert_eq!(field.error().get(), Some("required".to_string()));
+        assert_eq!(field.validate(), Err("required".to_string()));
+
+        field.value().set(String::from("again"));
+        assert!(field.is_valid().get());
+    }
+
+    #[test]
+    fn error_signal_notifies_on_change() {
+        let field = Validated::new(binding(5), |value: &i32| {
+            if *value % 2 == 0 {
+                Ok(())
+            } else {
+                Err("odd".to_string())
+            }
+        });
+
+        let errors: Rc<RefCell<Vec<Option<String>>>> = Rc::default();
+        let errors_clone = errors.clone();
+        let _guard = field.error().watch(move |ctx| {
+            errors_clone.borrow_mut().push(ctx.into_value());
+        });
+
+        field.value().set(4);
+        field.value().set(7);
+
+        assert_eq!(*errors.borrow(), [None, Some("odd".to_string())]);
+    }
+}
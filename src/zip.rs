@@ -3,9 +3,15 @@
 //! This module contains:
 //! - `Zip`: A structure to combine two `Signal` instances into one computation
 //!   that produces a tuple of their results.
+//! - `Zip3` through `Zip6`: Flat variants combining three to six signals into a
+//!   flat tuple, avoiding the `((A, B), C)` nesting produced by chaining `Zip`.
+//!   The `zip3` through `zip6` functions construct them.
 //! - `FlattenMap`: A trait for flattening and mapping nested tuple structures,
 //!   which simplifies working with multiple zipped computations.
 //!
+//! Tuples of up to six signals are signals themselves, so `(a, b).map(...)`
+//! works without an explicit wrapper.
+//!
 //! These utilities enable composition of reactive computations, making it easier
 //! to work with multiple interdependent values in a reactive context.
 
@@ -169,3 +175,241 @@ where
         (guard_a, guard_b)
     }
 }
+
+/// Defines a flat zip type over a fixed number of signals.
+///
+/// The signals are stored as a tuple and delegate to its `Signal`
+/// implementation in `nami-core`, where every source shares one `latest`
+/// tuple, so each notification carries the updated value alongside the most
+/// recent values of all other sources.
+macro_rules! flat_zip {
+    ($(#[$meta:meta])* $name:ident, $ctor:ident { $($field:ident: $ty:ident),+ }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone)]
+        pub struct $name<$($ty),+> {
+            signals: ($($ty,)+),
+        }
+
+        impl<$($ty),+> $name<$($ty),+>
+        where
+            $($ty: Signal, $ty::Output: Clone,)+
+        {
+            /// Creates a new flat zip over the given signals.
+            pub const fn new($($field: $ty),+) -> Self {
+                Self {
+                    signals: ($($field,)+),
+                }
+            }
+        }
+
+        impl<$($ty),+> Signal for $name<$($ty),+>
+        where
+            $($ty: Signal, $ty::Output: Clone,)+
+        {
+            type Output = ($($ty::Output,)+);
+            type Guard = ($($ty::Guard,)+);
+
+            fn get(&self) -> Self::Output {
+                self.signals.get()
+            }
+
+            fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+                self.signals.watch(watcher)
+            }
+        }
+
+        #[doc = concat!("Combines the given signals into a [`", stringify!($name), "`] producing a flat tuple.")]
+        pub const fn $ctor<$($ty),+>($($field: $ty),+) -> $name<$($ty),+>
+        where
+            $($ty: Signal, $ty::Output: Clone,)+
+        {
+            $name::new($($field),+)
+        }
+    };
+}
+
+flat_zip! {
+    /// Combines three signals into a single computation producing a flat `(A, B, C)` tuple.
+    Zip3, zip3 { a: A, b: B, c: C }
+}
+
+flat_zip! {
+    /// Combines four signals into a single computation producing a flat `(A, B, C, D)` tuple.
+    Zip4, zip4 { a: A, b: B, c: C, d: D }
+}
+
+flat_zip! {
+    /// Combines five signals into a single computation producing a flat `(A, B, C, D, E)` tuple.
+    Zip5, zip5 { a: A, b: B, c: C, d: D, e: E }
+}
+
+flat_zip! {
+    /// Combines six signals into a single computation producing a flat `(A, B, C, D, E, F)` tuple.
+    Zip6, zip6 { a: A, b: B, c: C, d: D, e: E, f: F }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Binding, SignalExt, binding};
+    use alloc::{vec, vec::Vec};
+
+    #[test]
+    fn zip3_produces_flat_tuple() {
+        let a: Binding<i32> = binding(1);
+        let b: Binding<i32> = binding(2);
+        let c: Binding<i32> = binding(3);
+        let sum = Zip3::new(a, b, c.clone()).map(|(a, b, c)| a + b + c);
+        assert_eq!(sum.get(), 6);
+
+        c.set(10);
+        assert_eq!(sum.get(), 13);
+    }
+
+    #[test]
+    fn zip4_propagates_every_input() {
+        let a: Binding<i32> = binding(1);
+        let b: Binding<i32> = binding(2);
+        let c: Binding<i32> = binding(3);
+        let d: Binding<i32> = binding(4);
+        let zipped = Zip4::new(a.clone(), b.clone(), c.clone(), d.clone());
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = zipped.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        a.set(10);
+        b.set(20);
+        c.set(30);
+        d.set(40);
+
+        assert_eq!(
+            *seen.borrow(),
+            vec![
+                (10, 2, 3, 4),
+                (10, 20, 3, 4),
+                (10, 20, 30, 4),
+                (10, 20, 30, 40),
+            ]
+        );
+    }
+
+    #[test]
+    fn zip3_stops_after_guard_dropped() {
+        let a: Binding<i32> = binding(1);
+        let b: Binding<i32> = binding(2);
+        let c: Binding<i32> = binding(3);
+        let zipped = Zip3::new(a.clone(), b, c);
+
+        let count = Rc::new(RefCell::new(0));
+        let count_clone = count.clone();
+        let guard = zipped.watch(move |_| *count_clone.borrow_mut() += 1);
+
+        a.set(5);
+        drop(guard);
+        a.set(6);
+        assert_eq!(*count.borrow(), 1);
+    }
+
+    #[test]
+    fn tuple_pair_maps_without_zip() {
+        let a: Binding<i32> = binding(1);
+        let b: Binding<i32> = binding(2);
+        let sum = (a, b.clone()).map(|(a, b)| a + b);
+        assert_eq!(sum.get(), 3);
+
+        b.set(10);
+        assert_eq!(sum.get(), 11);
+    }
+
+    #[test]
+    fn tuple_quad_propagates_every_input() {
+        let a: Binding<i32> = binding(1);
+        let b: Binding<i32> = binding(2);
+        let c: Binding<i32> = binding(3);
+        let d: Binding<i32> = binding(4);
+        let tuple = (a.clone(), b, c, d.clone());
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let _guard = tuple.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        a.set(10);
+        d.set(40);
+
+        assert_eq!(*seen.borrow(), vec![(10, 2, 3, 4), (10, 2, 3, 40)]);
+    }
+
+    #[test]
+    fn tuple_triple_stops_after_guard_dropped() {
+        let a: Binding<i32> = binding(1);
+        let b: Binding<&str> = binding("b");
+        let c: Binding<bool> = binding(false);
+        let tuple = (a.clone(), b.clone(), c.clone());
+
+        let count = Rc::new(RefCell::new(0));
+        let count_clone = count.clone();
+        let guard = tuple.watch(move |_| *count_clone.borrow_mut() += 1);
+
+        a.set(5);
+        drop(guard);
+        a.set(6);
+        b.set("c");
+        c.set(true);
+        assert_eq!(*count.borrow(), 1);
+        assert_eq!(tuple.get(), (6, "c", true));
+    }
+
+    #[test]
+    fn zip6_notifies_full_tuple_for_any_input() {
+        let inputs: Vec<Binding<i32>> = (1..=6).map(binding).collect();
+        let zipped = zip6(
+            inputs[0].clone(),
+            inputs[1].clone(),
+            inputs[2].clone(),
+            inputs[3].clone(),
+            inputs[4].clone(),
+            inputs[5].clone(),
+        );
+        assert_eq!(zipped.get(), (1, 2, 3, 4, 5, 6));
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let guard = zipped.watch(move |ctx| seen_clone.borrow_mut().push(ctx.into_value()));
+
+        inputs[4].set(50);
+        inputs[0].set(10);
+        assert_eq!(
+            *seen.borrow(),
+            vec![(1, 2, 3, 4, 50, 6), (10, 2, 3, 4, 50, 6)]
+        );
+
+        drop(guard);
+        for input in &inputs {
+            input.set(0);
+        }
+        assert_eq!(
+            seen.borrow().len(),
+            2,
+            "dropping the guard unsubscribes every input"
+        );
+    }
+
+    #[test]
+    fn zip5_combines_mixed_types() {
+        let name: Binding<&str> = binding("a");
+        let count: Binding<u8> = binding(1_u8);
+        let flag: Binding<bool> = binding(false);
+        let label = zip5(
+            name,
+            count.clone(),
+            flag,
+            binding::<f64>(0.5),
+            binding::<char>('x'),
+        )
+        .map(|(name, count, flag, ratio, mark)| alloc::format!("{name}{count}{flag}{ratio}{mark}"));
+
+        count.set(2);
+        assert_eq!(label.get(), "a2false0.5x");
+    }
+}
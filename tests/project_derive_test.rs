@@ -0,0 +1,129 @@
+#![allow(missing_docs)]
+#![cfg(feature = "derive")]
+
+use nami::{Binding, Project, binding};
+
+#[derive(Clone, Debug, PartialEq, Project)]
+struct Person {
+    name: String,
+    age: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Project)]
+struct Labeled {
+    label: &'static str,
+    value: i32,
+}
+
+#[test]
+fn test_snapshot_reflects_field_edits() {
+    let person: Binding<Person> = binding(Person {
+        name: "Alice".to_string(),
+        age: 30,
+    });
+    let projected = person.project();
+    assert_eq!(projected.snapshot(), person.get());
+
+    projected.name.set_from("Bob");
+    projected.age.set(31);
+
+    assert_eq!(
+        projected.snapshot(),
+        Person {
+            name: "Bob".to_string(),
+            age: 31,
+        }
+    );
+}
+
+#[test]
+fn test_snapshot_follows_source_changes() {
+    let labeled: Binding<Labeled> = binding(Labeled {
+        label: "count",
+        value: 1,
+    });
+    let projected = labeled.project();
+
+    labeled.set(Labeled {
+        label: "total",
+        value: 9,
+    });
+
+    let snapshot = projected.snapshot();
+    assert_eq!(snapshot.label, "total");
+    assert_eq!(snapshot.value, 9);
+}
+
+#[derive(Clone, Debug, PartialEq, Project)]
+#[project(lazy)]
+struct Settings {
+    theme: String,
+    font_size: u32,
+    line_numbers: bool,
+}
+
+fn settings() -> Settings {
+    Settings {
+        theme: "dark".to_string(),
+        font_size: 14,
+        line_numbers: true,
+    }
+}
+
+#[test]
+fn test_lazy_projection_only_builds_accessed_fields() {
+    let source: Binding<Settings> = binding(settings());
+    let projected = source.project();
+    assert!(projected.theme.get().is_none());
+    assert!(projected.font_size.get().is_none());
+
+    projected.font_size().set(16);
+
+    assert!(projected.font_size.get().is_some());
+    assert!(projected.theme.get().is_none());
+    assert!(projected.line_numbers.get().is_none());
+    assert_eq!(source.get().font_size, 16);
+}
+
+#[test]
+fn test_lazy_projection_reuses_field_binding() {
+    let source: Binding<Settings> = binding(settings());
+    let projected = source.project();
+
+    projected.theme().set_from("light");
+    assert_eq!(projected.theme().get(), "light");
+    source.set(Settings {
+        line_numbers: false,
+        ..settings()
+    });
+
+    assert!(!projected.line_numbers().get());
+    assert_eq!(projected.snapshot(), source.get());
+}
+
+#[derive(Clone, Debug, PartialEq, Project)]
+#[project(lazy)]
+struct Feed {
+    source: String,
+    unread: u32,
+}
+
+#[test]
+fn test_lazy_projection_allows_field_named_source() {
+    let feed: Binding<Feed> = binding(Feed {
+        source: "rss".to_string(),
+        unread: 2,
+    });
+    let projected = feed.project();
+
+    projected.source().set_from("atom");
+    projected.unread().set(0);
+
+    assert_eq!(
+        projected.snapshot(),
+        Feed {
+            source: "atom".to_string(),
+            unread: 0,
+        }
+    );
+}